@@ -0,0 +1,41 @@
+use core::str::from_utf8_unchecked as utf8;
+
+use crate::baseline::types::latitude::Latitude;
+use crate::baseline::types::longitude::Longitude;
+use crate::baseline::types::time::Time;
+use crate::baseline::types::{IntegerDecimal, Quality};
+
+#[derive(Clone, Default, Debug)]
+pub struct GGA {
+    pub time: Time,
+    pub latitude: Latitude,
+    pub longitude: Longitude,
+    pub quality: Quality,
+    pub num_satellites: u8,
+    pub hdop: IntegerDecimal,
+    /// meters
+    pub altitude: IntegerDecimal,
+}
+
+impl From<&[u8]> for GGA {
+    fn from(bytes: &[u8]) -> Self {
+        if bytes.iter().fold(0, |sum, &b| sum + (b == b',') as usize) < 9 {
+            return Self::default();
+        }
+        let mut fields = bytes.split(|&b| b == b',');
+        let time = Time::from(fields.next().unwrap());
+        let mut latitude = Latitude::from(fields.next().unwrap());
+        if fields.next().unwrap() == b"S" {
+            latitude.0 = -latitude.0;
+        }
+        let mut longitude = Longitude::from(fields.next().unwrap());
+        if fields.next().unwrap() == b"W" {
+            longitude.0 = -longitude.0;
+        }
+        let quality = Quality::from(fields.next().unwrap());
+        let num_satellites: u8 = unsafe { utf8(fields.next().unwrap()) }.parse().unwrap_or(0);
+        let hdop: IntegerDecimal = fields.next().unwrap().into();
+        let altitude: IntegerDecimal = fields.next().unwrap().into();
+        Self { time, latitude, longitude, quality, num_satellites, hdop, altitude }
+    }
+}
@@ -0,0 +1,19 @@
+use crate::baseline::types::{NavigationMode, OperationMode};
+
+#[derive(Clone, Default, Debug)]
+pub struct GSA {
+    pub operation_mode: OperationMode,
+    pub navigation_mode: NavigationMode,
+}
+
+impl From<&[u8]> for GSA {
+    fn from(bytes: &[u8]) -> Self {
+        if bytes.iter().fold(0, |sum, &b| sum + (b == b',') as usize) < 1 {
+            return Self::default();
+        }
+        let mut fields = bytes.split(|&b| b == b',');
+        let operation_mode = OperationMode::from(fields.next().unwrap());
+        let navigation_mode = NavigationMode::from(fields.next().unwrap());
+        Self { operation_mode, navigation_mode }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod gga;
+pub mod gns;
+pub mod gsa;
+pub mod rmc;
@@ -0,0 +1,51 @@
+use crate::baseline::types::date::Date;
+use crate::baseline::types::latitude::Latitude;
+use crate::baseline::types::longitude::Longitude;
+use crate::baseline::types::position_mode::PositionMode;
+use crate::baseline::types::time::Time;
+use crate::baseline::types::{IntegerDecimal, Status};
+
+#[derive(Clone, Default, Debug)]
+pub struct RMC {
+    pub time: Time,
+    pub status: Status,
+    pub latitude: Latitude,
+    pub longitude: Longitude,
+    /// knots
+    pub speed: IntegerDecimal,
+    pub course: IntegerDecimal,
+    pub date: Date,
+    pub heading: Option<IntegerDecimal>,
+    pub position_mode: PositionMode,
+}
+
+impl From<&[u8]> for RMC {
+    fn from(bytes: &[u8]) -> Self {
+        let mut fields = bytes.split(|&b| b == b',');
+        let time = Time::from(fields.next().unwrap());
+        let status = Status::from(fields.next().unwrap());
+        let mut latitude = Latitude::from(fields.next().unwrap());
+        if fields.next().unwrap() == b"S" {
+            latitude.0 = -latitude.0;
+        }
+        let mut longitude = Longitude::from(fields.next().unwrap());
+        if fields.next().unwrap() == b"W" {
+            longitude.0 = -longitude.0;
+        }
+        let speed: IntegerDecimal = fields.next().unwrap_or(b"0.0").into();
+        let course: IntegerDecimal = fields.next().unwrap().into();
+        let date: Date = fields.next().unwrap().into();
+        let mut heading: Option<IntegerDecimal> = None;
+        let field = fields.next().unwrap();
+        let mvew = fields.next().unwrap();
+        if field.len() > 0 {
+            let mut value: IntegerDecimal = field.into();
+            if mvew == b"W" {
+                value += 180;
+            }
+            heading = Some(value);
+        }
+        let position_mode = PositionMode::from(fields.next().unwrap());
+        Self { time, status, latitude, longitude, speed, course, date, heading, position_mode }
+    }
+}
@@ -0,0 +1,72 @@
+//! Parser and messages as of the baseline commit 289b405, copied verbatim except for crate
+//! paths and tests, so that benchmarks compare against the original implementation
+#![allow(clippy::all, dead_code)]
+
+pub mod message;
+pub mod messages;
+pub mod types;
+
+pub use message::{Message, SentenceFormatter};
+
+pub const MAX_MESSAGE_SIZE: usize = 79;
+
+pub struct Parser {
+    buffer: [u8; MAX_MESSAGE_SIZE],
+    index: usize,
+    enabled: u32,
+}
+
+impl Parser {
+    pub fn new() -> Self {
+        Self { buffer: [0u8; MAX_MESSAGE_SIZE], index: 0, enabled: u32::MAX }
+    }
+
+    pub fn with_enables(enableds: impl AsRef<[SentenceFormatter]>) -> Self {
+        let mut enabled: u32 = 0;
+        for &message in enableds.as_ref().iter() {
+            enabled |= 1 << (message as usize);
+        }
+        Self { buffer: [0u8; MAX_MESSAGE_SIZE], index: 0, enabled }
+    }
+
+    pub fn reset(&mut self) {
+        self.index = 0;
+    }
+
+    fn parse_line(&mut self, line: &[u8]) -> Option<Message> {
+        let mut line = line;
+        if !line.starts_with(b"$") || !line.ends_with(b"\r") {
+            if line.len() > self.buffer.len() - self.index {
+                self.index = 0;
+                return None;
+            }
+            self.buffer[self.index..self.index + line.len()].copy_from_slice(line);
+            self.index += line.len();
+            line = &self.buffer[..self.index];
+        }
+
+        if !line.ends_with(b"\r") {
+            return None;
+        }
+        self.index = 0;
+
+        if line.len() < 7 {
+            return None;
+        }
+
+        let option = SentenceFormatter::try_from(&line[3..6]);
+        if !option.map(|f| (1 << f as usize) & self.enabled > 0).unwrap_or(false) {
+            return None;
+        }
+
+        Message::try_from(&line[1..line.len() - 1])
+    }
+
+    pub fn parse_bytes<'a>(&'a mut self, bytes: &'a [u8]) -> impl Iterator<Item = Message> + 'a {
+        bytes
+            .split(|&b| b == b'\n')
+            .map(move |line| self.parse_line(line))
+            .filter(|o| o.is_some())
+            .map(|o| o.unwrap())
+    }
+}
@@ -0,0 +1,103 @@
+mod baseline;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use nmea0183_core::{MessageRef, Parser};
+
+/// One epoch of a multi-constellation receiver at default message configuration
+const EPOCH: &[&str] = &[
+    "GNRMC,083559.00,A,4717.11437,N,00833.91522,E,0.004,77.52,091202,,,A,V",
+    "GNVTG,77.52,T,,M,0.004,N,0.008,K,A",
+    "GNGGA,092725.00,4717.11399,N,00833.91590,E,1,08,1.01,499.6,M,48.0,M,,",
+    "GNGSA,A,3,23,29,07,08,09,18,26,28,,,,,1.94,1.18,1.54,1",
+    "GNGSA,A,3,65,66,67,,,,,,,,,,1.94,1.18,1.54,2",
+    "GPGSV,3,1,10,23,38,230,44,29,71,156,47,07,29,116,41,08,09,081,36,1",
+    "GPGSV,3,2,10,10,07,189,,05,05,220,,09,34,274,42,18,25,309,44,1",
+    "GPGSV,3,3,10,26,82,187,47,28,43,056,46,1",
+    "GLGSV,2,1,05,65,49,296,38,66,77,049,35,67,26,031,29,75,12,163,,1",
+    "GLGSV,2,2,05,76,38,218,31,1",
+    "GNGLL,4717.11364,N,00833.91565,E,092321.00,A,A",
+    "GNGST,172814.00,0.006,0.023,0.020,273.6,0.023,0.020,0.031",
+    "GNZDA,082710.00,16,09,2002,00,00",
+];
+
+/// Sentences known to the baseline parser, as output by the same receiver
+const SUBSET: &[&str] = &[
+    "GNRMC,083559.00,A,4717.11437,N,00833.91522,E,0.004,77.52,091202,,,A,V",
+    "GNGGA,092725.00,4717.11399,N,00833.91590,E,1,08,1.01,499.6,M,48.0,M,,",
+    "GNGNS,092725.00,4717.11399,N,00833.91590,E,AANN,11,1.01,499.6,48.0,,,V",
+    "GNGSA,A,3,23,29,07,08,09,18,26,28,,,,,1.94,1.18,1.54,1",
+    "GNGSA,A,3,65,66,67,,,,,,,,,,1.94,1.18,1.54,2",
+];
+
+fn stream(sentences: &[&str], epochs: usize) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for _ in 0..epochs {
+        for body in sentences {
+            let checksum = body.bytes().fold(0, |csum, b| csum ^ b);
+            bytes.extend_from_slice(format!("${}*{:02X}\r\n", body, checksum).as_bytes());
+        }
+    }
+    bytes
+}
+
+fn parser(c: &mut Criterion) {
+    let bytes = stream(SUBSET, 16);
+    let mut group = c.benchmark_group("subset");
+    group.throughput(Throughput::Bytes(bytes.len() as u64));
+
+    group.bench_function("baseline_parse_bytes", |b| {
+        let mut parser = baseline::Parser::new();
+        b.iter(|| parser.parse_bytes(black_box(&bytes)).count())
+    });
+
+    group.bench_function("parse_bytes", |b| {
+        let mut parser = Parser::new();
+        b.iter(|| parser.parse_bytes(black_box(&bytes)).filter(Result::is_ok).count())
+    });
+
+    group.bench_function("parse_bytes_ref", |b| {
+        let mut parser = Parser::new();
+        b.iter(|| {
+            let mut count = 0;
+            parser.parse_bytes_ref(black_box(&bytes), |result| count += result.is_ok() as usize);
+            count
+        })
+    });
+    group.finish();
+
+    let bytes = stream(EPOCH, 16);
+    let mut group = c.benchmark_group("mixed");
+    group.throughput(Throughput::Bytes(bytes.len() as u64));
+
+    group.bench_function("parse_bytes", |b| {
+        let mut parser = Parser::new();
+        b.iter(|| parser.parse_bytes(&bytes).filter(Result::is_ok).count())
+    });
+
+    group.bench_function("parse_bytes_chunked", |b| {
+        let mut parser = Parser::new();
+        b.iter(|| bytes.chunks(64).map(|chunk| parser.parse_bytes(chunk).count()).sum::<usize>())
+    });
+
+    group.bench_function("push", |b| {
+        let mut parser = Parser::new();
+        b.iter(|| bytes.iter().filter_map(|&byte| parser.push(byte)).count())
+    });
+
+    group.bench_function("parse_bytes_ref", |b| {
+        let mut parser = Parser::new();
+        b.iter(|| {
+            let mut count = 0;
+            parser.parse_bytes_ref(&bytes, |result| {
+                if let Ok(MessageRef::GGA(_, gga)) = result {
+                    count += gga.latitude().is_ok() as usize;
+                }
+            });
+            count
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, parser);
+criterion_main!(benches);
@@ -0,0 +1,31 @@
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ParseError {
+    /// line does not start with `$` or `!` followed by an address
+    InvalidFrame,
+    /// sentence exceeds parser capacity and is discarded
+    SentenceTooLong,
+    /// line ends without checksum
+    Truncated,
+    /// checksum is malformed or does not match
+    ChecksumMismatch,
+    /// sentence formatter not supported
+    UnknownSentence,
+    /// too few fields, index counted from first field after address
+    MissingField(usize),
+    /// field cannot be parsed, index counted from first field after address
+    InvalidField(usize),
+}
+
+impl core::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::InvalidFrame => write!(f, "invalid frame"),
+            Self::SentenceTooLong => write!(f, "sentence too long"),
+            Self::Truncated => write!(f, "truncated sentence"),
+            Self::ChecksumMismatch => write!(f, "checksum mismatch"),
+            Self::UnknownSentence => write!(f, "unknown sentence"),
+            Self::MissingField(index) => write!(f, "missing field {}", index),
+            Self::InvalidField(index) => write!(f, "invalid field {}", index),
+        }
+    }
+}
@@ -0,0 +1,497 @@
+use core::convert::TryFrom;
+use core::str::from_utf8;
+
+use crate::error::ParseError;
+use crate::message::checksum_matches;
+use crate::types::date::Date;
+use crate::types::latitude::Latitude;
+use crate::types::longitude::Longitude;
+use crate::types::position_mode::PositionMode;
+use crate::types::string::FixedString;
+use crate::types::system::System;
+use crate::types::time::Time;
+use crate::types::{IntegerDecimal, NavigationMode, NavigationalStatus, OperationMode, Status};
+
+/// Value of a single non-empty field
+pub trait FromField: Sized {
+    /// None if field is malformed
+    fn from_field(field: &[u8]) -> Option<Self>;
+}
+
+macro_rules! decimal {
+    ($($type:ty),+) => {
+        $(
+            impl FromField for $type {
+                fn from_field(field: &[u8]) -> Option<Self> {
+                    match integer(field) {
+                        Some(value) => Self::try_from(value).ok(),
+                        // sign or overflow
+                        None => from_utf8(field).ok()?.parse().ok(),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+decimal!(u8, u16, u32, i8);
+
+/// Hexadecimal field, e.g. system ID and signal ID
+#[derive(Default)]
+pub struct Hex<T>(pub T);
+
+macro_rules! hex {
+    ($($type:ty),+) => {
+        $(
+            impl FromField for Hex<$type> {
+                fn from_field(field: &[u8]) -> Option<Self> {
+                    let value = field.iter().try_fold(0 as $type, |value, &b| {
+                        let digit = (b as char).to_digit(16)? as $type;
+                        value.checked_mul(16)?.checked_add(digit)
+                    });
+                    value.map(Hex)
+                }
+            }
+        )+
+    };
+}
+
+hex!(u8, u16, u32);
+
+macro_rules! try_from {
+    ($($type:ty),+) => {
+        $(
+            impl FromField for $type {
+                fn from_field(field: &[u8]) -> Option<Self> {
+                    Self::try_from(field).ok()
+                }
+            }
+        )+
+    };
+}
+
+try_from!(Date, IntegerDecimal, Latitude, Longitude, Time);
+
+/// Single character status or mode indicator
+macro_rules! indicator {
+    ($($type:ty),+) => {
+        $(
+            impl FromField for $type {
+                fn from_field(field: &[u8]) -> Option<Self> {
+                    Self::try_from(field)
+                }
+            }
+        )+
+    };
+}
+
+indicator!(NavigationMode, NavigationalStatus, OperationMode, PositionMode, Status);
+
+/// ASCII text fitting in capacity
+impl<const N: usize> FromField for FixedString<N> {
+    fn from_field(field: &[u8]) -> Option<Self> {
+        let mut string = Self::default();
+        if string.push_bytes(field) {
+            Some(string)
+        } else {
+            None
+        }
+    }
+}
+
+/// Value of non-empty ASCII digits, none on overflow
+pub fn integer(digits: &[u8]) -> Option<u32> {
+    if digits.is_empty() {
+        return None;
+    }
+    digits.iter().try_fold(0u32, |value, &b| match b {
+        b'0'..=b'9' => value.checked_mul(10)?.checked_add((b - b'0') as u32),
+        _ => None,
+    })
+}
+
+/// Splits `integer.fraction` of ASCII digits, fraction scaled to given number of digits
+pub fn fixed_point(field: &[u8], digits: usize) -> Option<(u32, u32)> {
+    let mut splitted = field.splitn(2, |&b| b == b'.');
+    let integer = self::integer(splitted.next().unwrap_or(b""))?;
+    let fraction = splitted.next().unwrap_or(b"");
+    if !fraction.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    let mut value = 0;
+    for i in 0..digits {
+        value = value * 10 + fraction.get(i).map(|&b| (b - b'0') as u32).unwrap_or(0);
+    }
+    Some((integer, value))
+}
+
+/// Maximum number of fields split, further fields are merged into the last one
+pub const MAX_FIELDS: usize = 24;
+
+/// Comma separated fields of a sentence payload split by a single pass, which may also
+/// compute the checksum, keeps track of field index for errors. Only offsets are recorded,
+/// numeric fields are converted from their digits on access.
+pub struct Fields<'a> {
+    bytes: &'a [u8],
+    /// offset of first field
+    begin: usize,
+    /// end offset of each field but the last
+    ends: [u16; MAX_FIELDS],
+    end: usize,
+    length: usize,
+    /// index of first field split, preceding fields are skipped
+    first: usize,
+    index: usize,
+}
+
+/// Offsets of commas recorded while a payload arrives byte by byte, so that it is split into
+/// fields without being scanned again
+pub struct Commas {
+    offsets: [u16; MAX_FIELDS],
+    length: usize,
+}
+
+impl Default for Commas {
+    fn default() -> Self {
+        Self { offsets: [0; MAX_FIELDS], length: 0 }
+    }
+}
+
+impl Commas {
+    #[inline]
+    pub fn clear(&mut self) {
+        self.length = 0;
+    }
+
+    /// Commas after the address and MAX_FIELDS - 1 fields are dropped, merging further fields
+    /// into the last one
+    #[inline]
+    pub fn push(&mut self, offset: usize) {
+        if self.length < MAX_FIELDS && offset <= u16::MAX as usize {
+            self.offsets[self.length] = offset as u16;
+            self.length += 1;
+        }
+    }
+
+    /// Comma terminating the address, none if there is no field at all
+    #[inline]
+    pub fn address_end(&self) -> Option<usize> {
+        self.offsets[..self.length].first().map(|&offset| offset as usize)
+    }
+}
+
+impl<'a> Fields<'a> {
+    /// Splits bytes at commas into fields starting at index first, the address is split off
+    /// first if ADDRESSED and splitting stops at checksum delimiter if DELIMITED. Returns end
+    /// of address, XOR of bytes before checksum delimiter and position of checksum delimiter.
+    #[inline]
+    fn split<const ADDRESSED: bool, const DELIMITED: bool>(
+        bytes: &'a [u8],
+        first: usize,
+    ) -> (Self, usize, u8, Option<usize>) {
+        let mut fields =
+            Self { bytes, begin: 0, ends: [0; MAX_FIELDS], end: 0, length: 0, first, index: first };
+        let mut checksum = 0;
+        let is_delimiter = |b: u8| b == b',' || (DELIMITED && b == b'*');
+        let address_end = match ADDRESSED {
+            true => bytes.iter().position(|&b| is_delimiter(b)).unwrap_or(bytes.len()),
+            false => 0,
+        };
+        if ADDRESSED {
+            checksum = bytes[..address_end].iter().fold(0, |csum, &b| csum ^ b);
+            if bytes.get(address_end) != Some(&b',') {
+                // address without fields
+                fields.begin = address_end;
+                let star = Some(address_end).filter(|&i| i < bytes.len());
+                return (fields, address_end, checksum, star);
+            }
+            checksum ^= b',';
+            fields.begin = address_end + 1;
+        }
+
+        let mut star = None;
+        let mut end = bytes.len();
+        for (i, &b) in bytes.iter().enumerate().skip(fields.begin) {
+            if DELIMITED && b == b'*' {
+                star = Some(i);
+                end = i;
+                break;
+            }
+            checksum ^= b;
+            if b == b',' && first + fields.length < MAX_FIELDS - 1 && i <= u16::MAX as usize {
+                fields.push(i);
+            }
+        }
+        fields.push(end);
+        (fields, address_end, checksum, star)
+    }
+
+    /// Ends current field at given offset
+    #[inline]
+    fn push(&mut self, end: usize) {
+        self.ends[self.length] = end as u16;
+        self.end = end;
+        self.length += 1;
+    }
+
+    /// Splits fields after address
+    #[inline]
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self::split::<false, false>(bytes, 0).0
+    }
+
+    /// Starts at field of given index, e.g. to decode a single field lazily
+    #[inline]
+    pub fn at(bytes: &'a [u8], index: usize) -> Self {
+        // fields beyond MAX_FIELDS stay merged into the last one
+        let first = index.min(MAX_FIELDS - 1);
+        let mut start = 0;
+        for skipped in 0..first {
+            match bytes[start..].iter().position(|&b| b == b',') {
+                Some(position) => start += position + 1,
+                None => {
+                    let mut fields = Self::split::<false, false>(&bytes[bytes.len()..], skipped).0;
+                    fields.length = 0;
+                    fields.index = index;
+                    return fields;
+                }
+            }
+        }
+        let mut fields = Self::split::<false, false>(&bytes[start..], first).0;
+        fields.index = index;
+        fields
+    }
+
+    /// Splits address and fields of a sentence whose checksum is already verified
+    #[inline]
+    pub fn with_address(payload: &'a [u8]) -> (&'a [u8], Self) {
+        let (fields, address_end, _, _) = Self::split::<true, false>(payload, 0);
+        (&payload[..address_end], fields)
+    }
+
+    /// Splits address and fields of a sentence whose checksum is already verified at commas
+    /// recorded while it was received
+    #[inline]
+    pub fn with_commas(payload: &'a [u8], commas: &Commas) -> (&'a [u8], Self) {
+        let mut fields = Self {
+            bytes: payload,
+            begin: payload.len(),
+            ends: [0; MAX_FIELDS],
+            end: 0,
+            length: 0,
+            first: 0,
+            index: 0,
+        };
+        let address_end = match commas.address_end() {
+            Some(address_end) => address_end,
+            None => return (payload, fields),
+        };
+        fields.begin = address_end + 1;
+        for &offset in &commas.offsets[1..commas.length] {
+            fields.push(offset as usize);
+        }
+        fields.push(payload.len());
+        (&payload[..address_end], fields)
+    }
+
+    /// Splits address and fields of a line without start delimiter and trailing CR LF,
+    /// verifying checksum in the same pass
+    pub fn tokenize(line: &'a [u8]) -> Result<(&'a [u8], Self), ParseError> {
+        let (fields, address_end, checksum, star) = Self::split::<true, true>(line, 0);
+        let star = star.ok_or(ParseError::Truncated)?;
+        if !checksum_matches(&line[star + 1..], checksum) {
+            return Err(ParseError::ChecksumMismatch);
+        }
+        Ok((&line[..address_end], fields))
+    }
+
+    /// Number of fields not yet consumed
+    #[inline]
+    pub fn count(&self) -> usize {
+        (self.first + self.length).saturating_sub(self.index)
+    }
+
+    #[inline]
+    fn end(&self, index: usize) -> usize {
+        if index + 1 < self.length {
+            self.ends[index] as usize
+        } else {
+            self.end
+        }
+    }
+
+    #[inline]
+    fn field(&self, index: usize) -> &'a [u8] {
+        let start = if index > 0 { self.ends[index - 1] as usize + 1 } else { self.begin };
+        &self.bytes[start..self.end(index)]
+    }
+
+    /// Continues at field of given index, e.g. to decode fields by their position
+    #[inline]
+    pub fn seek(&mut self, index: usize) {
+        self.index = index.max(self.first);
+    }
+
+    #[inline]
+    pub fn next(&mut self) -> Result<&'a [u8], ParseError> {
+        if self.index >= self.first + self.length {
+            return Err(ParseError::MissingField(self.index));
+        }
+        self.index += 1;
+        Ok(self.field(self.index - self.first - 1))
+    }
+
+    /// Fields added by later NMEA versions are empty if absent
+    #[inline]
+    pub fn next_or_empty(&mut self) -> &'a [u8] {
+        self.next().unwrap_or(b"")
+    }
+
+    /// Remaining payload including commas, e.g. free text
+    pub fn rest(&mut self) -> Result<&'a [u8], ParseError> {
+        let field = self.next()?;
+        let start = self.end(self.index - self.first - 1) - field.len();
+        self.length = self.index - self.first;
+        Ok(&self.bytes[start..self.end])
+    }
+
+    /// Field converted by From, e.g. datum codes and indicators accepting any character
+    pub fn parse_from<T: From<&'a [u8]>>(&mut self) -> Result<T, ParseError> {
+        Ok(T::from(self.next()?))
+    }
+
+    /// Absent field converted by From as if empty
+    pub fn parse_trailing_from<T: From<&'a [u8]>>(&mut self) -> Result<T, ParseError> {
+        Ok(T::from(self.next_or_empty()))
+    }
+
+    #[inline]
+    fn convert<T: FromField>(field: &[u8], index: usize) -> Result<Option<T>, ParseError> {
+        if field.is_empty() {
+            return Ok(None);
+        }
+        T::from_field(field).map(Some).ok_or(ParseError::InvalidField(index))
+    }
+
+    /// Empty field yields default value
+    pub fn parse<T: FromField + Default>(&mut self) -> Result<T, ParseError> {
+        Ok(self.parse_option()?.unwrap_or_default())
+    }
+
+    /// Empty field yields none
+    pub fn parse_option<T: FromField>(&mut self) -> Result<Option<T>, ParseError> {
+        let index = self.index;
+        Self::convert(self.next()?, index)
+    }
+
+    /// Absent or empty field yields none
+    pub fn parse_trailing<T: FromField>(&mut self) -> Result<Option<T>, ParseError> {
+        let index = self.index;
+        Self::convert(self.next_or_empty(), index)
+    }
+
+    /// Hexadecimal field, empty field yields zero
+    pub fn parse_hex<T: Default>(&mut self) -> Result<T, ParseError>
+    where
+        Hex<T>: FromField,
+    {
+        Ok(self.parse::<Hex<T>>()?.0)
+    }
+
+    /// NMEA 4.10 system ID, absent, empty or unknown ID yields none
+    pub fn parse_system(&mut self) -> Result<Option<System>, ParseError> {
+        Ok(self.parse_trailing()?.and_then(|Hex(id)| System::from_id(id)))
+    }
+
+    /// NMEA 4.10 signal ID, absent or empty field yields none
+    pub fn parse_signal_id(&mut self) -> Result<Option<u8>, ParseError> {
+        Ok(self.parse_trailing()?.map(|Hex(id)| id))
+    }
+
+    /// Latitude followed by N or S, southern latitude is negative
+    pub fn parse_latitude(&mut self) -> Result<Option<Latitude>, ParseError> {
+        let latitude: Option<Latitude> = self.parse_option()?;
+        let south = self.next()? == b"S";
+        Ok(latitude.map(|latitude| if south { Latitude(-latitude.0) } else { latitude }))
+    }
+
+    /// Longitude followed by E or W, western longitude is negative
+    pub fn parse_longitude(&mut self) -> Result<Option<Longitude>, ParseError> {
+        let longitude: Option<Longitude> = self.parse_option()?;
+        let west = self.next()? == b"W";
+        Ok(longitude.map(|longitude| if west { Longitude(-longitude.0) } else { longitude }))
+    }
+}
+
+mod test {
+    #[test]
+    fn test_fields() {
+        use super::{fixed_point, Commas, Fields, Hex, MAX_FIELDS};
+        use crate::error::ParseError;
+
+        let mut fields = Fields::new(b"1,,x,1F,a,b");
+        assert_eq!(6, fields.count());
+        assert_eq!(Ok(1u8), fields.parse());
+        assert_eq!(Ok(None::<u8>), fields.parse_option());
+        assert_eq!(Err(ParseError::InvalidField(2)), fields.parse::<u8>());
+        assert_eq!(Ok(Some(0x1f)), fields.parse_option::<Hex<u8>>().map(|h| h.map(|Hex(v)| v)));
+        assert_eq!(Ok(&b"a,b"[..]), fields.rest());
+        assert_eq!(Err(ParseError::MissingField(5)), fields.next());
+        assert_eq!(Ok(None::<u8>), fields.parse_trailing());
+
+        let mut fields = Fields::at(b"1,2,3", 1);
+        assert_eq!(Ok(Some(2u8)), fields.parse_option());
+        assert_eq!(Err(ParseError::InvalidField(2)), Fields::at(b"1,2,x", 2).parse::<u8>());
+        let mut fields = Fields::at(b"1,2,3", 4);
+        assert_eq!(Err(ParseError::MissingField(4)), fields.next());
+        let mut fields = Fields::at(b"0,1,2,3,4,5,6,7,8,9", 2);
+        assert_eq!(8, fields.count());
+        let values: Vec<u8> = (2..10).map(|_| fields.parse().unwrap()).collect();
+        assert_eq!(vec![2, 3, 4, 5, 6, 7, 8, 9], values);
+        assert_eq!(Err(ParseError::MissingField(10)), fields.next());
+        assert_eq!(Ok(&b"5,6,7,8,9"[..]), Fields::at(b"0,1,2,3,4,5,6,7,8,9", 5).rest());
+
+        let (address, mut fields) =
+            Fields::tokenize(b"GPZDA,082710.00,16,09,2002,00,00*64").unwrap();
+        assert_eq!(b"GPZDA", address);
+        assert_eq!(6, fields.count());
+        assert_eq!(Ok(&b"082710.00"[..]), fields.next());
+        assert_eq!(Ok(16u8), fields.parse());
+        assert_eq!(Ok(9u8), fields.parse());
+        assert_eq!(Err(ParseError::InvalidField(3)), fields.parse::<u8>());
+        assert_eq!(Err(ParseError::ChecksumMismatch), Fields::tokenize(b"GPZDA,1*64").map(|_| ()));
+        assert_eq!(Err(ParseError::Truncated), Fields::tokenize(b"GPZDA,1").map(|_| ()));
+
+        let (address, fields) = Fields::with_address(b"GPGGA");
+        assert_eq!((&b"GPGGA"[..], 0), (address, fields.count()));
+
+        let mut commas = Commas::default();
+        let (address, fields) = Fields::with_commas(b"GPGGA", &commas);
+        assert_eq!((&b"GPGGA"[..], 0), (address, fields.count()));
+        let payload = b"GPZDA,082710.00,16,,2002";
+        payload.iter().enumerate().filter(|(_, &b)| b == b',').for_each(|(i, _)| commas.push(i));
+        let (address, mut fields) = Fields::with_commas(payload, &commas);
+        assert_eq!((&b"GPZDA"[..], 4), (address, fields.count()));
+        assert_eq!(Ok(&b"082710.00"[..]), fields.next());
+        assert_eq!(Ok(16u8), fields.parse());
+        assert_eq!(Ok(None::<u8>), fields.parse_option());
+        assert_eq!(Ok(Some(2002u16)), fields.parse_option());
+        assert_eq!(Err(ParseError::MissingField(4)), fields.next());
+
+        let bytes = b"1,".repeat(MAX_FIELDS + 1);
+        let mut fields = Fields::new(&bytes);
+        assert_eq!(MAX_FIELDS, fields.count());
+        assert_eq!(Ok(Some(1u8)), fields.parse_option());
+        assert_eq!(Ok(&b"1,1,"[..]), Fields::at(&bytes, MAX_FIELDS - 1).rest());
+        assert_eq!(Err(ParseError::InvalidField(23)), Fields::at(&bytes, 23).parse::<u8>());
+
+        let mut fields = Fields::new(b"4717.11399,S,00833.91590,W");
+        assert_eq!(Ok(Some(-471711399)), fields.parse_latitude().map(|l| l.map(|l| l.0)));
+        assert_eq!(Ok(Some(-83391590)), fields.parse_longitude().map(|l| l.map(|l| l.0)));
+
+        assert_eq!(Some((4717, 11390)), fixed_point(b"4717.1139", 5));
+        assert_eq!(Some((92725, 0)), fixed_point(b"092725", 2));
+        assert_eq!(None, fixed_point(b".5", 2));
+        assert_eq!(None, fixed_point(b"1.-5", 2));
+    }
+}
@@ -0,0 +1,142 @@
+use crate::message::SentenceFormatter;
+use crate::types::talker::TalkerId;
+
+pub const MAX_TALKER_OVERRIDES: usize = 8;
+
+#[derive(Copy, Clone, Debug)]
+struct Override {
+    talker: TalkerId,
+    enabled: u32,
+    disabled: u32,
+}
+
+/// Decides which talker and sentence formatter combinations are parsed,
+/// per talker overrides take precedence over formatters enabled for all talkers
+#[derive(Clone, Debug)]
+pub struct Filter {
+    enabled: u32,
+    overrides: [Option<Override>; MAX_TALKER_OVERRIDES],
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+impl Filter {
+    pub fn all() -> Self {
+        Self { enabled: u32::MAX, overrides: [None; MAX_TALKER_OVERRIDES] }
+    }
+
+    pub fn none() -> Self {
+        Self { enabled: 0, overrides: [None; MAX_TALKER_OVERRIDES] }
+    }
+
+    pub fn with_enables(enableds: impl AsRef<[SentenceFormatter]>) -> Self {
+        let mut filter = Self::none();
+        for &formatter in enableds.as_ref().iter() {
+            filter.enable(formatter);
+        }
+        filter
+    }
+
+    /// Enables formatter for talkers without override
+    pub fn enable(&mut self, formatter: SentenceFormatter) {
+        self.enabled |= 1 << formatter as usize;
+    }
+
+    /// Disables formatter for talkers without override
+    pub fn disable(&mut self, formatter: SentenceFormatter) {
+        self.enabled &= !(1 << formatter as usize);
+    }
+
+    fn find_or_insert(&mut self, talker: TalkerId) -> Option<&mut Override> {
+        let mut index = self.overrides.iter().position(|o| o.map(|o| o.talker) == Some(talker));
+        if index.is_none() {
+            index = self.overrides.iter().position(|o| o.is_none());
+            let entry = Override { talker, enabled: 0, disabled: 0 };
+            self.overrides[index?] = Some(entry);
+        }
+        self.overrides[index?].as_mut()
+    }
+
+    /// Returns false if there are already MAX_TALKER_OVERRIDES talkers overridden
+    pub fn enable_talker(&mut self, talker: TalkerId, formatter: SentenceFormatter) -> bool {
+        let bit = 1 << formatter as usize;
+        match self.find_or_insert(talker) {
+            Some(entry) => {
+                entry.enabled |= bit;
+                entry.disabled &= !bit;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns false if there are already MAX_TALKER_OVERRIDES talkers overridden
+    pub fn disable_talker(&mut self, talker: TalkerId, formatter: SentenceFormatter) -> bool {
+        let bit = 1 << formatter as usize;
+        match self.find_or_insert(talker) {
+            Some(entry) => {
+                entry.disabled |= bit;
+                entry.enabled &= !bit;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Removes all overrides of talker
+    pub fn reset_talker(&mut self, talker: TalkerId) {
+        for entry in self.overrides.iter_mut() {
+            if entry.map(|o| o.talker) == Some(talker) {
+                *entry = None;
+            }
+        }
+    }
+
+    pub fn is_enabled(&self, talker: TalkerId, formatter: SentenceFormatter) -> bool {
+        let bit = 1 << formatter as usize;
+        let mut enabled = self.enabled;
+        if let Some(o) = self.overrides.iter().flatten().find(|o| o.talker == talker) {
+            enabled = (enabled | o.enabled) & !o.disabled;
+        }
+        enabled & bit > 0
+    }
+}
+
+mod test {
+    #[cfg(all(feature = "gga", feature = "gsv", feature = "rmc"))]
+    #[test]
+    fn test_filter() {
+        use super::{Filter, MAX_TALKER_OVERRIDES};
+        use crate::message::SentenceFormatter::{GGA, GSV, RMC};
+        use crate::types::talker::TalkerId;
+
+        let mut filter = Filter::with_enables([GGA, RMC]);
+        assert!(filter.is_enabled(TalkerId::GP, GGA));
+        assert!(!filter.is_enabled(TalkerId::GP, GSV));
+
+        filter.disable(GGA);
+        assert!(filter.enable_talker(TalkerId::GN, GGA));
+        assert!(filter.is_enabled(TalkerId::GN, GGA));
+        assert!(!filter.is_enabled(TalkerId::GP, GGA));
+        assert!(!filter.is_enabled(TalkerId::GL, GGA));
+
+        assert!(filter.disable_talker(TalkerId::GN, RMC));
+        assert!(filter.is_enabled(TalkerId::GP, RMC));
+        assert!(!filter.is_enabled(TalkerId::GN, RMC));
+        assert!(filter.is_enabled(TalkerId::GN, GGA));
+
+        filter.reset_talker(TalkerId::GN);
+        assert!(!filter.is_enabled(TalkerId::GN, GGA));
+        assert!(filter.is_enabled(TalkerId::GN, RMC));
+
+        for i in 0..MAX_TALKER_OVERRIDES {
+            assert!(filter.enable_talker(TalkerId::Other([b'X', b'0' + i as u8]), GSV));
+        }
+        assert!(!filter.enable_talker(TalkerId::GP, GSV));
+        assert!(filter.enable_talker(TalkerId::Other(*b"X0"), GGA));
+    }
+}
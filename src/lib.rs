@@ -1,109 +1,512 @@
 #![cfg_attr(not(test), no_std)]
+// field helpers and types shared by sentences are partly unused without every sentence feature
+#![cfg_attr(
+    not(all(
+        feature = "alm",
+        feature = "dtm",
+        feature = "gbs",
+        feature = "gga",
+        feature = "gll",
+        feature = "gns",
+        feature = "grs",
+        feature = "gsa",
+        feature = "gst",
+        feature = "gsv",
+        feature = "rmc",
+        feature = "txt",
+        feature = "vtg",
+        feature = "zda",
+    )),
+    allow(dead_code)
+)]
 
 #[cfg(test)]
 #[macro_use]
 extern crate pretty_assertions;
 
+pub mod error;
+mod fields;
+pub mod filter;
 pub mod message;
 pub mod messages;
+pub mod sentence;
 pub mod types;
 
+pub use error::ParseError;
+use fields::{Commas, Fields};
+pub use filter::Filter;
+#[cfg(feature = "any-sentence")]
+pub use message::MessageRef;
+use message::{checksum_matches, parse_address};
 pub use message::{Message, SentenceFormatter};
+pub use sentence::RawSentence;
+use types::datum::{Datum, Datums};
+use types::talker::TalkerId;
 
-pub const MAX_MESSAGE_SIZE: usize = 79;
+/// Maximum sentence length including start delimiter and CR LF defined by the standard
+pub const MAX_MESSAGE_SIZE: usize = 82;
 
-pub struct Parser {
-    buffer: [u8; MAX_MESSAGE_SIZE],
+/// Accepts sentences of at most N bytes including start delimiter and CR LF,
+/// use `Parser::<N>::default()` or `Parser::<N>::filtered` for longer proprietary sentences
+pub struct Parser<const N: usize = MAX_MESSAGE_SIZE> {
+    buffer: [u8; N],
     index: usize,
-    enabled: u32,
+    /// sentence started by push and not yet terminated
+    receiving: bool,
+    /// running checksum of pushed bytes up to checksum delimiter
+    checksum: u8,
+    /// position of checksum delimiter in pushed bytes
+    checksum_index: Option<usize>,
+    /// commas of pushed bytes up to checksum delimiter
+    commas: Commas,
+    /// buffer holds bytes pushed rather than a partial line of parse_bytes
+    pushing: bool,
+    /// remainder of sentence too long for buffer is dropped up to next LF
+    discarding: bool,
+    filter: Filter,
+    datums: Datums,
+}
+
+impl<const N: usize> Default for Parser<N> {
+    fn default() -> Self {
+        Self::filtered(Filter::all())
+    }
 }
 
 impl Parser {
     pub fn new() -> Self {
-        Self { buffer: [0u8; MAX_MESSAGE_SIZE], index: 0, enabled: u32::MAX }
+        Self::with_filter(Filter::all())
     }
 
     pub fn with_enables(enableds: impl AsRef<[SentenceFormatter]>) -> Self {
-        let mut enabled: u32 = 0;
-        for &message in enableds.as_ref().iter() {
-            enabled |= 1 << (message as usize);
+        Self::with_filter(Filter::with_enables(enableds))
+    }
+
+    pub fn with_filter(filter: Filter) -> Self {
+        Self::filtered(filter)
+    }
+}
+
+impl<const N: usize> Parser<N> {
+    /// Counterpart of with_filter for any capacity, e.g. `Parser::<128>::filtered(filter)`
+    pub fn filtered(filter: Filter) -> Self {
+        Self {
+            buffer: [0u8; N],
+            index: 0,
+            receiving: false,
+            checksum: 0,
+            checksum_index: None,
+            commas: Commas::default(),
+            pushing: false,
+            discarding: false,
+            filter,
+            datums: Datums::default(),
         }
-        Self { buffer: [0u8; MAX_MESSAGE_SIZE], index: 0, enabled }
+    }
+
+    pub fn filter(&self) -> &Filter {
+        &self.filter
+    }
+
+    /// Allows changing enabled talkers and sentence formatters at runtime
+    pub fn filter_mut(&mut self) -> &mut Filter {
+        &mut self.filter
     }
 
     pub fn reset(&mut self) {
         self.index = 0;
+        self.receiving = false;
+        self.discarding = false;
     }
 
-    fn parse_line(&mut self, line: &[u8]) -> Option<Message> {
-        let mut line = line;
-        if !line.starts_with(b"$") || !line.ends_with(b"\r") {
-            if line.len() > self.buffer.len() - self.index {
-                self.index = 0;
-                return None;
+    /// Drops partial sentence of the other input mode before buffering bytes of this one
+    fn switch(&mut self, pushing: bool) {
+        if self.pushing != pushing {
+            self.reset();
+            self.pushing = pushing;
+        }
+    }
+
+    /// Local datum announced by latest DTM sentence of talker, attached to subsequent
+    /// positions of the same talker
+    pub fn datum(&self, talker: TalkerId) -> Datum {
+        self.datums.get(talker)
+    }
+
+    /// Joins line with buffered partial line, returns complete sentence without trailing CR,
+    /// buffer holds sentence without trailing LF
+    fn frame<'a>(
+        buffer: &'a mut [u8],
+        index: &mut usize,
+        discarding: &mut bool,
+        line: &'a [u8],
+    ) -> Result<Option<&'a [u8]>, ParseError> {
+        let terminated = line.ends_with(b"\n");
+        let mut line = line.strip_suffix(b"\n").unwrap_or(line);
+        // start delimiter always begins a new sentence, dropping whatever precedes it
+        if let Some(start) = line.iter().rposition(|&b| b == b'$' || b == b'!') {
+            *index = 0;
+            *discarding = false;
+            line = &line[start..];
+        }
+        if *discarding {
+            *discarding = !terminated;
+            return Ok(None);
+        }
+        let delimited = line.starts_with(b"$") || line.starts_with(b"!");
+        if delimited && line.ends_with(b"\r") {
+            if line.len() > buffer.len() {
+                *index = 0;
+                return Err(ParseError::SentenceTooLong);
+            }
+        } else {
+            if line.len() > buffer.len() - *index {
+                *index = 0;
+                *discarding = !terminated;
+                return Err(ParseError::SentenceTooLong);
             }
-            self.buffer[self.index..self.index + line.len()].copy_from_slice(line);
-            self.index += line.len();
-            line = &self.buffer[..self.index];
+            buffer[*index..*index + line.len()].copy_from_slice(line);
+            *index += line.len();
+            line = &buffer[..*index];
         }
 
         if !line.ends_with(b"\r") {
+            return Ok(None);
+        }
+        *index = 0;
+        Ok(Some(&line[..line.len() - 1]))
+    }
+
+    /// Applies filter to address of a sentence with verified checksum, also selects DTM not
+    /// enabled along with whether the sentence is enabled
+    fn select(
+        filter: &Filter,
+        address: &[u8],
+    ) -> Option<Result<(TalkerId, SentenceFormatter, bool), ParseError>> {
+        let (talker, formatter) = match parse_address(address) {
+            Ok(parsed) => parsed,
+            Err(error) => return Some(Err(error)),
+        };
+        let enabled = filter.is_enabled(talker, formatter);
+        // DTM always parsed to keep track of datum
+        #[cfg(feature = "dtm")]
+        let selected = enabled || formatter == SentenceFormatter::DTM;
+        #[cfg(not(feature = "dtm"))]
+        let selected = enabled;
+        if !selected {
             return None;
         }
-        self.index = 0;
+        Some(Ok((talker, formatter, enabled)))
+    }
 
-        if line.len() < 7 {
+    /// Applies filter to a sentence with verified checksum and keeps track of datum
+    fn accept(
+        filter: &Filter,
+        datums: &mut Datums,
+        address: &[u8],
+        fields: Fields,
+    ) -> Option<Result<Message, ParseError>> {
+        let (talker, formatter, enabled) = match Self::select(filter, address)? {
+            Ok(selected) => selected,
+            Err(error) => return Some(Err(error)),
+        };
+        let mut message = match Message::from_fields(talker, formatter, fields) {
+            Ok(message) => message,
+            Err(error) => return if enabled { Some(Err(error)) } else { None },
+        };
+        Self::track_datum(datums, &mut message);
+        if !enabled {
             return None;
         }
+        Some(Ok(message))
+    }
 
-        let option = SentenceFormatter::try_from(&line[3..6]);
-        if !option.map(|f| (1 << f as usize) & self.enabled > 0).unwrap_or(false) {
+    /// Borrowing counterpart of accept, fields other than the local datum of DTM are left
+    /// to the caller
+    #[cfg(feature = "any-sentence")]
+    fn accept_ref<'a>(
+        filter: &Filter,
+        datums: &mut Datums,
+        address: &[u8],
+        fields: Option<&'a [u8]>,
+    ) -> Option<Result<MessageRef<'a>, ParseError>> {
+        let (talker, formatter, enabled) = match Self::select(filter, address)? {
+            Ok(selected) => selected,
+            Err(error) => return Some(Err(error)),
+        };
+        let message = match MessageRef::from_fields(talker, formatter, fields) {
+            Ok(message) => message,
+            Err(error) => return if enabled { Some(Err(error)) } else { None },
+        };
+        if let Err(error) = Self::track_datum_ref(datums, &message) {
+            return if enabled { Some(Err(error)) } else { None };
+        }
+        if !enabled {
             return None;
         }
+        Some(Ok(message))
+    }
+
+    /// Keeps track of local datum announced by DTM and attaches it to positions of the same
+    /// talker
+    #[allow(unused_variables, unreachable_patterns)] // arms depend on sentence features
+    fn track_datum(datums: &mut Datums, message: &mut Message) {
+        match message {
+            #[cfg(feature = "dtm")]
+            Message::DTM(talker, dtm) => datums.set(*talker, dtm.local_datum),
+            #[cfg(feature = "gga")]
+            Message::GGA(talker, gga) => gga.datum = datums.get(*talker),
+            #[cfg(feature = "gll")]
+            Message::GLL(talker, gll) => gll.datum = datums.get(*talker),
+            #[cfg(feature = "gns")]
+            Message::GNS(talker, gns) => gns.datum = datums.get(*talker),
+            #[cfg(feature = "rmc")]
+            Message::RMC(talker, rmc) => rmc.datum = datums.get(*talker),
+            _ => (),
+        }
+    }
+
+    /// Borrowing counterpart of track_datum, positions are left to the caller
+    #[cfg(feature = "any-sentence")]
+    #[allow(unused_variables, irrefutable_let_patterns)] // depends on sentence features
+    fn track_datum_ref(datums: &mut Datums, message: &MessageRef) -> Result<(), ParseError> {
+        #[cfg(feature = "dtm")]
+        if let MessageRef::DTM(talker, dtm) = message {
+            datums.set(*talker, dtm.decode()?.local_datum);
+        }
+        Ok(())
+    }
+
+    /// Frames line and checks start delimiter, None if line is incomplete
+    fn line<'a>(
+        buffer: &'a mut [u8],
+        index: &mut usize,
+        discarding: &mut bool,
+        line: &'a [u8],
+    ) -> Option<Result<&'a [u8], ParseError>> {
+        let line = match Self::frame(buffer, index, discarding, line) {
+            Ok(line) => line?,
+            Err(error) => return Some(Err(error)),
+        };
+        let delimited = line.starts_with(b"$") || line.starts_with(b"!");
+        if !delimited {
+            return Some(Err(ParseError::InvalidFrame));
+        }
+        Some(Ok(&line[1..]))
+    }
+
+    /// None if line is incomplete or sentence is not enabled
+    fn parse_line(&mut self, line: &[u8]) -> Option<Result<Message, ParseError>> {
+        let buffer = &mut self.buffer[..N.saturating_sub(1)];
+        let line = match Self::line(buffer, &mut self.index, &mut self.discarding, line)? {
+            Ok(line) => line,
+            Err(error) => return Some(Err(error)),
+        };
+        match Fields::tokenize(line) {
+            Ok((address, fields)) => Self::accept(&self.filter, &mut self.datums, address, fields),
+            Err(error) => Some(Err(error)),
+        }
+    }
+
+    /// Yields a result for every complete line except sentences not enabled
+    pub fn parse_bytes<'a>(
+        &'a mut self,
+        bytes: &'a [u8],
+    ) -> impl Iterator<Item = Result<Message, ParseError>> + 'a {
+        self.switch(false);
+        bytes.split_inclusive(|&b| b == b'\n').filter_map(move |line| self.parse_line(line))
+    }
 
-        Message::try_from(&line[1..line.len() - 1])
+    /// Borrowing counterpart of parse_bytes, fields are decoded on access only
+    #[cfg(feature = "any-sentence")]
+    pub fn parse_bytes_ref(
+        &mut self,
+        bytes: &[u8],
+        mut f: impl FnMut(Result<MessageRef, ParseError>),
+    ) {
+        self.switch(false);
+        for line in bytes.split_inclusive(|&b| b == b'\n') {
+            let buffer = &mut self.buffer[..N.saturating_sub(1)];
+            let result = match Self::line(buffer, &mut self.index, &mut self.discarding, line) {
+                Some(Ok(line)) => match message::verify_checksum(line) {
+                    Ok(payload) => {
+                        let mut splitted = payload.splitn(2, |&b| b == b',');
+                        let address = splitted.next().unwrap_or(b"");
+                        Self::accept_ref(&self.filter, &mut self.datums, address, splitted.next())
+                    }
+                    Err(error) => Some(Err(error)),
+                },
+                Some(Err(error)) => Some(Err(error)),
+                None => None,
+            };
+            if let Some(result) = result {
+                f(result)
+            }
+        }
     }
 
-    pub fn parse_bytes<'a>(&'a mut self, bytes: &'a [u8]) -> impl Iterator<Item = Message> + 'a {
-        bytes
-            .split(|&b| b == b'\n')
-            .map(move |line| self.parse_line(line))
-            .filter(|o| o.is_some())
-            .map(|o| o.unwrap())
+    /// Feeds a single byte, e.g. from UART interrupt handler, yields a result once
+    /// a sentence is terminated by CR or LF. Bytes outside sentences are ignored and
+    /// a start delimiter always begins a new sentence.
+    ///
+    /// Shares buffer with parse_bytes, switching between them drops a partially received
+    /// sentence.
+    pub fn push(&mut self, byte: u8) -> Option<Result<Message, ParseError>> {
+        match self.receive(byte)? {
+            Ok(length) => {
+                let (address, fields) = Fields::with_commas(&self.buffer[..length], &self.commas);
+                Self::accept(&self.filter, &mut self.datums, address, fields)
+            }
+            Err(error) => Some(Err(error)),
+        }
+    }
+
+    /// Borrowing counterpart of push, message borrows the parser until the next byte
+    #[cfg(feature = "any-sentence")]
+    pub fn push_ref(&mut self, byte: u8) -> Option<Result<MessageRef<'_>, ParseError>> {
+        match self.receive(byte)? {
+            Ok(length) => {
+                let payload = &self.buffer[..length];
+                let (address, fields) = match self.commas.address_end() {
+                    Some(end) => (&payload[..end], Some(&payload[end + 1..])),
+                    None => (payload, None),
+                };
+                Self::accept_ref(&self.filter, &mut self.datums, address, fields)
+            }
+            Err(error) => Some(Err(error)),
+        }
+    }
+
+    /// Buffers a single byte, yields length of payload once a sentence with matching
+    /// checksum is terminated
+    fn receive(&mut self, byte: u8) -> Option<Result<usize, ParseError>> {
+        self.switch(true);
+        match byte {
+            b'$' | b'!' => {
+                let interrupted = self.receiving;
+                self.receiving = true;
+                self.index = 0;
+                self.checksum = 0;
+                self.checksum_index = None;
+                self.commas.clear();
+                if interrupted {
+                    return Some(Err(ParseError::Truncated));
+                }
+                None
+            }
+            _ if !self.receiving => None,
+            b'\r' | b'\n' => {
+                self.receiving = false;
+                let length = self.index;
+                self.index = 0;
+                let checksum_index = match self.checksum_index {
+                    Some(index) => index,
+                    None => return Some(Err(ParseError::Truncated)),
+                };
+                if !checksum_matches(&self.buffer[checksum_index + 1..length], self.checksum) {
+                    return Some(Err(ParseError::ChecksumMismatch));
+                }
+                Some(Ok(checksum_index))
+            }
+            // start delimiter and CR LF not buffered
+            _ if self.index >= N.saturating_sub(3) => {
+                self.receiving = false;
+                self.index = 0;
+                Some(Err(ParseError::SentenceTooLong))
+            }
+            _ => {
+                match self.checksum_index {
+                    Some(_) => (),
+                    None if byte == b'*' => self.checksum_index = Some(self.index),
+                    None if byte == b',' => {
+                        self.commas.push(self.index);
+                        self.checksum ^= byte
+                    }
+                    None => self.checksum ^= byte,
+                }
+                self.buffer[self.index] = byte;
+                self.index += 1;
+                None
+            }
+        }
+    }
+
+    /// Yields every framed sentence including unknown and proprietary ones,
+    /// regardless of enabled sentence formatters, along with lines failing to frame
+    pub fn parse_raw_bytes(
+        &mut self,
+        bytes: &[u8],
+        mut f: impl FnMut(Result<RawSentence, ParseError>),
+    ) {
+        self.switch(false);
+        for line in bytes.split_inclusive(|&b| b == b'\n') {
+            let buffer = &mut self.buffer[..N.saturating_sub(1)];
+            match Self::frame(buffer, &mut self.index, &mut self.discarding, line) {
+                Ok(Some(line)) => f(RawSentence::try_from(line).ok_or(ParseError::InvalidFrame)),
+                Ok(None) => (),
+                Err(error) => f(Err(error)),
+            }
+        }
     }
 }
 
 mod test {
+    #[cfg(all(
+        feature = "gga",
+        feature = "gll",
+        feature = "gns",
+        feature = "rmc",
+        feature = "vtg"
+    ))]
     #[test]
     fn test_parser() {
         use super::Parser;
         use crate::message::Message;
+        use crate::types::talker::TalkerId;
 
         let bytes = b"blablabla$GPGLL,4717.11364,N,00833.91565,E,092321.00,A,A*60\r\n\
                       $GPGGA,092725.00,4717.11399,N,00833.91590,E,1,08,1.01,499.6,M,48.0,M,,*5B\r\n\
                       $GNGNS,103600.01,5114.51176,N,00012.29380,W,ANNN,07,1.18,111.5,45.6,,,V*00\r\n\
                       $GPRMC,083559.00,A,4717.11437,N,00833.91522,E,0.004,77.52,091202,,,A,V*2D\r\n\
-                      $GPGLL,4717.11364,N,00833.91565,E,092321.00,A,A*60\r\n";
+                      $GPGLL,4717.11364,N,00833.91565,E,092321.00,A,A*60\r\n\
+                      $GPVTG,77.52,T,,M,0.004,N,0.008,K,A*06\r\n";
         let mut parser = Parser::new();
         {
             let mut messages = parser.parse_bytes(bytes);
-            match messages.next().unwrap() {
-                Message::GGA(gga) => assert_eq!("499.6#1", format!("{:?}", gga.altitude)),
+            match messages.next().unwrap().unwrap() {
+                Message::GLL(_, gll) => assert_eq!("09:23:21.00", format!("{:?}", gll.time)),
+                _ => panic!(),
+            }
+            match messages.next().unwrap().unwrap() {
+                Message::GGA(_, gga) => assert_eq!("Some(499.6#1)", format!("{:?}", gga.altitude)),
+                _ => panic!(),
+            }
+            match messages.next().unwrap().unwrap() {
+                Message::GNS(talker, gns) => {
+                    assert_eq!(TalkerId::GN, talker);
+                    assert_eq!("Some(111.5#1)", format!("{:?}", gns.altitude))
+                }
                 _ => panic!(),
             }
-            match messages.next().unwrap() {
-                Message::GNS(gns) => assert_eq!("111.5#1", format!("{:?}", gns.altitude)),
+            match messages.next().unwrap().unwrap() {
+                Message::RMC(_, rmc) => assert_eq!("Some(0.4#3)", format!("{:?}", rmc.speed)),
                 _ => panic!(),
             }
-            match messages.next().unwrap() {
-                Message::RMC(rmc) => assert_eq!("0.4#3", format!("{:?}", rmc.speed)),
+            match messages.next().unwrap().unwrap() {
+                Message::GLL(_, gll) => assert_eq!("09:23:21.00", format!("{:?}", gll.time)),
+                _ => panic!(),
+            }
+            match messages.next().unwrap().unwrap() {
+                Message::VTG(_, vtg) => assert_eq!("0.8#3", format!("{:?}", vtg.speed_kph)),
                 _ => panic!(),
             }
             assert!(messages.next().is_none());
         }
 
         {
-            assert!(parser.parse_bytes(&bytes[0..64]).next().is_none());
+            let mut results = parser.parse_bytes(&bytes[0..64]);
+            assert!(results.next().unwrap().is_ok());
+            assert!(results.next().is_none());
         }
         {
             assert!(parser.parse_bytes(&bytes[64..128]).next().is_none());
@@ -124,4 +527,309 @@ mod test {
             assert!(results.next().is_none());
         }
     }
+
+    #[cfg(all(feature = "gga", feature = "gll", feature = "zda"))]
+    #[test]
+    fn test_parser_errors() {
+        use super::{ParseError, Parser};
+
+        let bytes = b"$GPGLL,4717.11364,N,00833.91565,E*00\r\n\
+                      $GPGLL,4717.11364,N,00833.91565,E\r\n\
+                      $GPXYZ,1,2,3*50\r\n\
+                      $GPGLL,4717.11364,N,00833.91565,E*69\r\n\
+                      $GPZDA,082710.00,16,x9,2002,00,00*2C\r\n\
+                      $GPGGA\r\n\
+                      $PUBX,00,081350.00,4717.113210,N*5B\r\n\
+                      $GP*17\r\n\
+                      garbage\r\n";
+        let mut parser = Parser::new();
+        let errors: Vec<_> = parser.parse_bytes(bytes).map(|result| result.err()).collect();
+        let expected = [
+            ParseError::ChecksumMismatch,
+            ParseError::Truncated,
+            ParseError::UnknownSentence,
+            ParseError::MissingField(4),
+            ParseError::InvalidField(2),
+            ParseError::Truncated,
+            ParseError::UnknownSentence,
+            ParseError::InvalidFrame,
+            ParseError::InvalidFrame,
+        ];
+        assert_eq!(expected.iter().map(|&e| Some(e)).collect::<Vec<_>>(), errors);
+
+        let mut bytes = [b'A'; 100];
+        bytes[0] = b'$';
+        let mut results = parser.parse_bytes(&bytes);
+        assert_eq!(Some(Err(ParseError::SentenceTooLong)), results.next().map(|r| r.map(|_| ())));
+    }
+
+    #[cfg(all(feature = "gga", feature = "gll", feature = "vtg", feature = "zda"))]
+    #[test]
+    fn test_parser_push() {
+        use super::{ParseError, Parser};
+        use crate::message::Message;
+
+        let bytes = b"xx$GPGLL,4717.11364,N,00833.91565,E,092321.00,A,A*60\r\n\
+                      $GPGGA,092725.00,4717.11399,N,00833.91590,E,1,08,1.01,499.6,M,48.0,M,,*5B\n\
+                      $GPVTG,77.52,T,,M,0.004,N,0.008,K,A*06\r\
+                      $GPGLL,4717$GPZDA,082710.00,16,09,2002,00,00*64\r\n\
+                      $GPGLL,4717.11364,N,00833.91565,E*00\r\n";
+        let mut parser = Parser::new();
+        let mut results = bytes.iter().filter_map(|&b| parser.push(b));
+        match results.next().unwrap().unwrap() {
+            Message::GLL(_, gll) => assert_eq!("09:23:21.00", format!("{:?}", gll.time)),
+            _ => panic!(),
+        }
+        match results.next().unwrap().unwrap() {
+            Message::GGA(_, gga) => assert_eq!(Some(8), gga.num_satellites),
+            _ => panic!(),
+        }
+        match results.next().unwrap().unwrap() {
+            Message::VTG(_, vtg) => assert_eq!("0.8#3", format!("{:?}", vtg.speed_kph)),
+            _ => panic!(),
+        }
+        assert_eq!(Some(ParseError::Truncated), results.next().unwrap().err());
+        match results.next().unwrap().unwrap() {
+            Message::ZDA(_, zda) => assert_eq!(2002, zda.year),
+            _ => panic!(),
+        }
+        assert_eq!(Some(ParseError::ChecksumMismatch), results.next().unwrap().err());
+        assert!(results.next().is_none());
+
+        let mut parser = Parser::new();
+        let mut bytes = [b'A'; 100];
+        bytes[0] = b'$';
+        let errors: Vec<_> = bytes.iter().filter_map(|&b| parser.push(b)).collect();
+        assert_eq!(1, errors.len());
+        assert_eq!(Some(ParseError::SentenceTooLong), errors[0].as_ref().err().copied());
+        assert!(parser.push(b'\r').is_none());
+
+        // switching input mode drops the partial sentence of the other one
+        let gll = b"$GPGLL,4717.11364,N,00833.91565,E,092321.00,A,A*60\r\n";
+        let gga = b"$GPGGA,092725.00,4717.11399,N,00833.91590,E,1,08,1.01,499.6,M,48.0,M,,*5B\r\n";
+        let mut parser = Parser::new();
+        assert_eq!(0, gll[..20].iter().filter_map(|&b| parser.push(b)).count());
+        assert!(matches!(parser.parse_bytes(gga).next(), Some(Ok(Message::GGA(..)))));
+        assert_eq!(0, gll[20..].iter().filter_map(|&b| parser.push(b)).count());
+        assert!(parser.parse_bytes(&gga[..20]).next().is_none());
+        let mut results = gll.iter().filter_map(|&b| parser.push(b));
+        assert!(matches!(results.next(), Some(Ok(Message::GLL(..)))));
+        let result = parser.parse_bytes(&gga[20..]).next();
+        assert_eq!(Some(ParseError::InvalidFrame), result.and_then(Result::err));
+    }
+
+    #[cfg(all(feature = "dtm", feature = "gga", feature = "gll", feature = "zda"))]
+    #[test]
+    fn test_parser_ref() {
+        use super::{ParseError, Parser};
+        use crate::message::{MessageRef, SentenceFormatter};
+        use crate::types::datum::Datum;
+        use crate::types::talker::TalkerId;
+
+        let bytes =
+            b"$GPGGA,092725.00,4717.11399,N,00833.91590,E,1,08,1.01,499.6,M,48.0,M,,*5B\r\n\
+                      $GPDTM,999,,0.08,N,0.07,E,-47.7,W84*1B\r\n\
+                      $GPZDA,082710.00,16,x9,2002,00,00*2C\r\n\
+                      $GPGLL,4717.11364,N,00833.91565,E,092321.00,A,A*60\r\n";
+        let mut parser: Parser =
+            Parser::with_enables([SentenceFormatter::GGA, SentenceFormatter::ZDA]);
+        let mut results = Vec::new();
+        parser.parse_bytes_ref(&bytes[..100], |result| results.push(format!("{:?}", result)));
+        parser.parse_bytes_ref(&bytes[100..], |result| match result {
+            Ok(MessageRef::ZDA(_, zda)) => {
+                assert_eq!(Ok(2002), zda.year());
+                assert_eq!(Err(ParseError::InvalidField(2)), zda.month());
+                assert!(zda.decode().is_err());
+            }
+            _ => panic!(),
+        });
+        assert_eq!(1, results.len());
+        assert!(results[0].starts_with("Ok(GGA(GP, GGARef("));
+        assert_eq!(Datum::UserDefined, parser.datum(TalkerId::GP));
+
+        let mut parser = Parser::new();
+        let mut count = 0;
+        for &byte in bytes.iter() {
+            match parser.push_ref(byte) {
+                None => continue,
+                Some(Ok(MessageRef::GGA(_, gga))) => {
+                    assert_eq!(Ok(Some(8)), gga.num_satellites());
+                    assert_eq!(Datum::WGS84, gga.decode().unwrap().datum);
+                }
+                Some(Ok(MessageRef::DTM(_, _))) => {
+                    assert_eq!(Datum::UserDefined, parser.datum(TalkerId::GP))
+                }
+                Some(Ok(MessageRef::ZDA(_, _))) => (),
+                Some(Ok(MessageRef::GLL(talker, gll))) => {
+                    assert_eq!("GP", format!("{:?}", talker));
+                    assert_eq!("Ok(09:23:21.00)", format!("{:?}", gll.time()));
+                }
+                result => panic!("{:?}", result),
+            }
+            count += 1;
+        }
+        assert_eq!(4, count);
+
+        let line = b"GPGLL,4717.11364,N,00833.91565,E,092321.00,A,A*60";
+        let message = MessageRef::try_from(line).unwrap();
+        assert!(message.decode().is_ok());
+        assert_eq!(
+            Err(ParseError::ChecksumMismatch),
+            MessageRef::try_from(b"GPGLL,1*00").map(|_| ())
+        );
+        assert_eq!(
+            Err(ParseError::UnknownSentence),
+            MessageRef::try_from(b"GPXYZ,1*51").map(|_| ())
+        );
+        assert_eq!(
+            Err(ParseError::UnknownSentence),
+            MessageRef::try_from(b"PUBX,00*33").map(|_| ())
+        );
+    }
+
+    #[cfg(feature = "gga")]
+    #[test]
+    fn test_parser_capacity() {
+        use super::{Filter, ParseError, Parser};
+
+        let bytes = b"$GPGGA,092725.00,4717.113990000,N,00833.915900000,E,1,08,1.01,499.6,M,\
+                      48.0,M,1.0,0000*74\r\n";
+        // constructors need no annotation for default capacity
+        let mut parser = Parser::new();
+        let result = parser.parse_bytes(bytes).next().unwrap();
+        assert_eq!(Some(ParseError::SentenceTooLong), result.err());
+        assert!(parser.parse_bytes(&bytes[..50]).next().is_none());
+        let result = parser.parse_bytes(&bytes[50..]).next().unwrap();
+        assert_eq!(Some(ParseError::SentenceTooLong), result.err());
+        let result = bytes.iter().filter_map(|&b| parser.push(b)).next().unwrap();
+        assert_eq!(Some(ParseError::SentenceTooLong), result.err());
+
+        let mut parser = Parser::<90>::default();
+        assert!(parser.parse_bytes(bytes).next().unwrap().is_ok());
+        assert!(parser.parse_bytes(&bytes[..50]).next().is_none());
+        assert!(parser.parse_bytes(&bytes[50..]).next().unwrap().is_ok());
+        assert!(bytes.iter().filter_map(|&b| parser.push(b)).next().unwrap().is_ok());
+
+        let mut parser = Parser::<89>::default();
+        assert!(parser.parse_bytes(bytes).next().unwrap().is_err());
+        assert!(bytes.iter().filter_map(|&b| parser.push(b)).next().unwrap().is_err());
+
+        // remainder of oversize sentence split across chunks is dropped without further errors
+        let mut parser = Parser::<32>::filtered(Filter::all());
+        let mut errors: Vec<_> = parser.parse_bytes(&bytes[..50]).map(Result::err).collect();
+        errors.extend(parser.parse_bytes(&bytes[50..]).map(Result::err));
+        assert_eq!(vec![Some(ParseError::SentenceTooLong)], errors);
+        let mut errors = Vec::new();
+        parser.parse_bytes_ref(&bytes[..50], |result| errors.push(result.err()));
+        parser.parse_bytes_ref(&bytes[50..], |result| errors.push(result.err()));
+        assert_eq!(vec![Some(ParseError::SentenceTooLong)], errors);
+        let mut errors = Vec::new();
+        parser.parse_raw_bytes(&bytes[..50], |result| errors.push(result.err()));
+        parser.parse_raw_bytes(&bytes[50..], |result| errors.push(result.err()));
+        assert_eq!(vec![Some(ParseError::SentenceTooLong)], errors);
+    }
+
+    #[cfg(all(feature = "gga", feature = "zda"))]
+    #[test]
+    fn test_parser_with_enables() {
+        use super::Parser;
+        use crate::message::{Message, SentenceFormatter};
+
+        let bytes =
+            b"$GPGGA,092725.00,4717.11399,N,00833.91590,E,1,08,1.01,499.6,M,48.0,M,,*5B\r\n\
+                      $GPZDA,082710.00,16,09,2002,00,00*64\r\n";
+        let mut parser = Parser::with_enables([SentenceFormatter::ZDA]);
+        let mut messages = parser.parse_bytes(bytes);
+        match messages.next().unwrap().unwrap() {
+            Message::ZDA(_, zda) => assert_eq!(2002, zda.year),
+            _ => panic!(),
+        }
+        assert!(messages.next().is_none());
+    }
+
+    #[cfg(all(feature = "dtm", feature = "gga"))]
+    #[test]
+    fn test_parser_datum() {
+        use super::Parser;
+        use crate::message::{Message, SentenceFormatter};
+        use crate::types::datum::Datum;
+        use crate::types::talker::TalkerId;
+
+        let bytes = b"$GPGGA,092725.00,4717.11399,N,00833.91590,E,1,08,1.01,499.6,M,48.0,M,,*5B\r\n\
+                      $GPDTM,999,,0.08,N,0.07,E,-47.7,W84*1B\r\n\
+                      $GLDTM,P90,,0.0,N,0.0,E,0.0,W84*71\r\n\
+                      $GPGGA,092725.00,4717.11399,N,00833.91590,E,1,08,1.01,499.6,M,48.0,M,,*5B\r\n";
+        let mut parser = Parser::with_enables([SentenceFormatter::GGA]);
+        {
+            let mut messages = parser.parse_bytes(bytes);
+            match messages.next().unwrap().unwrap() {
+                Message::GGA(_, gga) => assert_eq!(Datum::WGS84, gga.datum),
+                _ => panic!(),
+            }
+            match messages.next().unwrap().unwrap() {
+                Message::GGA(_, gga) => assert_eq!(Datum::UserDefined, gga.datum),
+                _ => panic!(),
+            }
+            assert!(messages.next().is_none());
+        }
+        assert_eq!(Datum::UserDefined, parser.datum(TalkerId::GP));
+        assert_eq!(Datum::PE90, parser.datum(TalkerId::GL));
+        assert_eq!(Datum::WGS84, parser.datum(TalkerId::GN));
+    }
+
+    #[test]
+    fn test_parse_raw_bytes() {
+        use super::{ParseError, Parser, RawSentence};
+        use crate::sentence::ChecksumStatus;
+        use crate::types::talker::TalkerId;
+
+        let bytes = b"$PUBX,00,081350.00,4717.113210,N*00\r\n\
+                      $GPGGA,092725.00,4717.11399,N,00833.91590,E,1,08,1.01,499.6,M,48.0,M,,*5B\r\n\
+                      !AIVDM,1,1,,A,13aEOK?P00PD2wVMdLDRhgvL289?,0*26\r\n\
+                      $GPXYZ,1,2,3*50\r\n\
+                      garbage\r\n";
+        let mut parser = Parser::with_enables([]);
+        let mut talkers = Vec::new();
+        let mut errors = Vec::new();
+        let mut callback = |result: Result<RawSentence, ParseError>| match result {
+            Ok(sentence) => {
+                let invalid = sentence.checksum_status() == ChecksumStatus::Invalid;
+                assert_eq!(sentence.is_proprietary(), invalid);
+                talkers.push(sentence.talker());
+            }
+            Err(error) => errors.push(error),
+        };
+        parser.parse_raw_bytes(&bytes[..100], &mut callback);
+        parser.parse_raw_bytes(&bytes[100..], &mut callback);
+        let mut long = [b'A'; 100];
+        long[0] = b'$';
+        parser.parse_raw_bytes(&long, &mut callback);
+        assert_eq!(vec![None, Some(TalkerId::GP), Some(TalkerId::AI), Some(TalkerId::GP)], talkers);
+        assert_eq!(vec![ParseError::InvalidFrame, ParseError::SentenceTooLong], errors);
+    }
+
+    #[cfg(all(feature = "gll", feature = "gns"))]
+    #[test]
+    fn test_parser_filter() {
+        use super::Parser;
+        use crate::message::{Message, SentenceFormatter};
+        use crate::types::talker::TalkerId;
+
+        let bytes =
+            b"$GNGNS,103600.01,5114.51176,N,00012.29380,W,ANNN,07,1.18,111.5,45.6,,,V*00\r\n\
+                      $GPGLL,4717.11364,N,00833.91565,E,092321.00,A,A*60\r\n";
+        let mut parser = Parser::new();
+        assert_eq!(2, parser.parse_bytes(bytes).count());
+
+        parser.filter_mut().disable(SentenceFormatter::GLL);
+        assert_eq!(1, parser.parse_bytes(bytes).count());
+
+        parser.filter_mut().disable_talker(TalkerId::GN, SentenceFormatter::GNS);
+        parser.filter_mut().enable_talker(TalkerId::GP, SentenceFormatter::GLL);
+        let message = parser.parse_bytes(bytes).next().unwrap().unwrap();
+        match message {
+            Message::GLL(talker, _) => assert_eq!(TalkerId::GP, talker),
+            _ => panic!(),
+        }
+    }
 }
@@ -1,25 +1,102 @@
-use core::str::from_utf8_unchecked;
+use core::fmt::{Display, Write};
+use core::str::from_utf8;
 
-use crate::messages::gga::GGA;
-use crate::messages::gns::GNS;
-use crate::messages::gsa::GSA;
-use crate::messages::rmc::RMC;
+use crate::error::ParseError;
+use crate::fields::Fields;
+#[cfg(feature = "alm")]
+use crate::messages::alm::{ALMRef, ALM};
+#[cfg(feature = "dtm")]
+use crate::messages::dtm::{DTMRef, DTM};
+#[cfg(feature = "gbs")]
+use crate::messages::gbs::{GBSRef, GBS};
+#[cfg(feature = "gga")]
+use crate::messages::gga::{GGARef, GGA};
+#[cfg(feature = "gll")]
+use crate::messages::gll::{GLLRef, GLL};
+#[cfg(feature = "gns")]
+use crate::messages::gns::{GNSRef, GNS};
+#[cfg(feature = "grs")]
+use crate::messages::grs::{GRSRef, GRS};
+#[cfg(feature = "gsa")]
+use crate::messages::gsa::{GSARef, GSA};
+#[cfg(feature = "gst")]
+use crate::messages::gst::{GSTRef, GST};
+#[cfg(feature = "gsv")]
+use crate::messages::gsv::{GSVRef, GSV};
+#[cfg(feature = "rmc")]
+use crate::messages::rmc::{RMCRef, RMC};
+#[cfg(feature = "txt")]
+use crate::messages::txt::{TXTRef, TXT};
+#[cfg(feature = "vtg")]
+use crate::messages::vtg::{VTGRef, VTG};
+#[cfg(feature = "zda")]
+use crate::messages::zda::{ZDARef, ZDA};
+use crate::sentence::Address;
+use crate::types::talker::TalkerId;
 
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum SentenceFormatter {
-    GGA = 0,
+    #[cfg(feature = "gga")]
+    GGA,
+    #[cfg(feature = "gns")]
     GNS,
+    #[cfg(feature = "gsa")]
     GSA,
+    #[cfg(feature = "rmc")]
     RMC,
+    #[cfg(feature = "gll")]
+    GLL,
+    #[cfg(feature = "vtg")]
+    VTG,
+    #[cfg(feature = "gsv")]
+    GSV,
+    #[cfg(feature = "zda")]
+    ZDA,
+    #[cfg(feature = "gst")]
+    GST,
+    #[cfg(feature = "gbs")]
+    GBS,
+    #[cfg(feature = "dtm")]
+    DTM,
+    #[cfg(feature = "grs")]
+    GRS,
+    #[cfg(feature = "txt")]
+    TXT,
+    #[cfg(feature = "alm")]
+    ALM,
 }
 
 impl SentenceFormatter {
     pub fn try_from(bytes: &[u8]) -> Option<SentenceFormatter> {
         match bytes {
+            #[cfg(feature = "gga")]
             b"GGA" => Some(Self::GGA),
+            #[cfg(feature = "gns")]
             b"GNS" => Some(Self::GNS),
+            #[cfg(feature = "gsa")]
             b"GSA" => Some(Self::GSA),
+            #[cfg(feature = "rmc")]
             b"RMC" => Some(Self::RMC),
+            #[cfg(feature = "gll")]
+            b"GLL" => Some(Self::GLL),
+            #[cfg(feature = "vtg")]
+            b"VTG" => Some(Self::VTG),
+            #[cfg(feature = "gsv")]
+            b"GSV" => Some(Self::GSV),
+            #[cfg(feature = "zda")]
+            b"ZDA" => Some(Self::ZDA),
+            #[cfg(feature = "gst")]
+            b"GST" => Some(Self::GST),
+            #[cfg(feature = "gbs")]
+            b"GBS" => Some(Self::GBS),
+            #[cfg(feature = "dtm")]
+            b"DTM" => Some(Self::DTM),
+            #[cfg(feature = "grs")]
+            b"GRS" => Some(Self::GRS),
+            #[cfg(feature = "txt")]
+            b"TXT" => Some(Self::TXT),
+            #[cfg(feature = "alm")]
+            b"ALM" => Some(Self::ALM),
             _ => None,
         }
     }
@@ -27,43 +104,335 @@ impl SentenceFormatter {
 
 #[derive(Clone, Debug)]
 pub enum Message {
-    GNS(GNS),
-    GGA(GGA),
-    GSA(GSA),
-    RMC(RMC),
+    #[cfg(feature = "gns")]
+    GNS(TalkerId, GNS),
+    #[cfg(feature = "gga")]
+    GGA(TalkerId, GGA),
+    #[cfg(feature = "gsa")]
+    GSA(TalkerId, GSA),
+    #[cfg(feature = "rmc")]
+    RMC(TalkerId, RMC),
+    #[cfg(feature = "gll")]
+    GLL(TalkerId, GLL),
+    #[cfg(feature = "vtg")]
+    VTG(TalkerId, VTG),
+    #[cfg(feature = "gsv")]
+    GSV(TalkerId, GSV),
+    #[cfg(feature = "zda")]
+    ZDA(TalkerId, ZDA),
+    #[cfg(feature = "gst")]
+    GST(TalkerId, GST),
+    #[cfg(feature = "gbs")]
+    GBS(TalkerId, GBS),
+    #[cfg(feature = "dtm")]
+    DTM(TalkerId, DTM),
+    #[cfg(feature = "grs")]
+    GRS(TalkerId, GRS),
+    #[cfg(feature = "txt")]
+    TXT(TalkerId, TXT),
+    #[cfg(feature = "alm")]
+    ALM(TalkerId, ALM),
 }
 
 impl Message {
-    pub fn try_from(line: &[u8]) -> Option<Message> {
-        let mut splitted = line.rsplitn(2, |&b| b == b'*');
-        let checksum = match splitted.next() {
-            Some(c) => u8::from_str_radix(unsafe { from_utf8_unchecked(c) }, 16).unwrap_or(0),
-            None => return None,
-        };
-
-        let payload = match splitted.next() {
-            Some(v) => v,
-            None => return None,
-        };
-
-        if payload.iter().fold(0, |csum, &b| csum ^ b) != checksum {
-            return None;
+    pub fn talker(&self) -> TalkerId {
+        match *self {
+            #[cfg(feature = "gns")]
+            Self::GNS(talker, _) => talker,
+            #[cfg(feature = "gga")]
+            Self::GGA(talker, _) => talker,
+            #[cfg(feature = "gsa")]
+            Self::GSA(talker, _) => talker,
+            #[cfg(feature = "rmc")]
+            Self::RMC(talker, _) => talker,
+            #[cfg(feature = "gll")]
+            Self::GLL(talker, _) => talker,
+            #[cfg(feature = "vtg")]
+            Self::VTG(talker, _) => talker,
+            #[cfg(feature = "gsv")]
+            Self::GSV(talker, _) => talker,
+            #[cfg(feature = "zda")]
+            Self::ZDA(talker, _) => talker,
+            #[cfg(feature = "gst")]
+            Self::GST(talker, _) => talker,
+            #[cfg(feature = "gbs")]
+            Self::GBS(talker, _) => talker,
+            #[cfg(feature = "dtm")]
+            Self::DTM(talker, _) => talker,
+            #[cfg(feature = "grs")]
+            Self::GRS(talker, _) => talker,
+            #[cfg(feature = "txt")]
+            Self::TXT(talker, _) => talker,
+            #[cfg(feature = "alm")]
+            Self::ALM(talker, _) => talker,
         }
+    }
+
+    /// Accepts a line without start delimiter and trailing CR LF
+    pub fn try_from(line: &[u8]) -> Result<Message, ParseError> {
+        Self::from_payload(verify_checksum(line)?)
+    }
+
+    /// Accepts address and fields of a sentence whose checksum is already verified
+    pub fn from_payload(payload: &[u8]) -> Result<Message, ParseError> {
+        let (address, fields) = Fields::with_address(payload);
+        let (talker, formatter) = parse_address(address)?;
+        Self::from_fields(talker, formatter, fields)
+    }
+
+    /// Decodes fields split along with the address
+    #[allow(unused_variables, unreachable_code)] // arms depend on sentence features
+    pub(crate) fn from_fields(
+        talker: TalkerId,
+        formatter: SentenceFormatter,
+        fields: Fields,
+    ) -> Result<Message, ParseError> {
+        Ok(match formatter {
+            #[cfg(feature = "gga")]
+            SentenceFormatter::GGA => Message::GGA(talker, GGA::from_fields(fields)?),
+            #[cfg(feature = "gns")]
+            SentenceFormatter::GNS => Message::GNS(talker, GNS::from_fields(fields)?),
+            #[cfg(feature = "gsa")]
+            SentenceFormatter::GSA => Message::GSA(talker, GSA::from_fields(fields)?),
+            #[cfg(feature = "rmc")]
+            SentenceFormatter::RMC => Message::RMC(talker, RMC::from_fields(fields)?),
+            #[cfg(feature = "gll")]
+            SentenceFormatter::GLL => Message::GLL(talker, GLL::from_fields(fields)?),
+            #[cfg(feature = "vtg")]
+            SentenceFormatter::VTG => Message::VTG(talker, VTG::from_fields(fields)?),
+            #[cfg(feature = "gsv")]
+            SentenceFormatter::GSV => Message::GSV(talker, GSV::from_fields(fields)?),
+            #[cfg(feature = "zda")]
+            SentenceFormatter::ZDA => Message::ZDA(talker, ZDA::from_fields(fields)?),
+            #[cfg(feature = "gst")]
+            SentenceFormatter::GST => Message::GST(talker, GST::from_fields(fields)?),
+            #[cfg(feature = "gbs")]
+            SentenceFormatter::GBS => Message::GBS(talker, GBS::from_fields(fields)?),
+            #[cfg(feature = "dtm")]
+            SentenceFormatter::DTM => Message::DTM(talker, DTM::from_fields(fields)?),
+            #[cfg(feature = "grs")]
+            SentenceFormatter::GRS => Message::GRS(talker, GRS::from_fields(fields)?),
+            #[cfg(feature = "txt")]
+            SentenceFormatter::TXT => Message::TXT(talker, TXT::from_fields(fields)?),
+            #[cfg(feature = "alm")]
+            SentenceFormatter::ALM => Message::ALM(talker, ALM::from_fields(fields)?),
+        })
+    }
+}
+
+/// Talker and sentence formatter of address, well-formed addresses not recognised including
+/// proprietary ones are unknown
+pub(crate) fn parse_address(address: &[u8]) -> Result<(TalkerId, SentenceFormatter), ParseError> {
+    match Address::parse(address).ok_or(ParseError::InvalidFrame)? {
+        Address::Standard(talker, formatter) => {
+            let formatter =
+                SentenceFormatter::try_from(formatter).ok_or(ParseError::UnknownSentence)?;
+            Ok((talker, formatter))
+        }
+        Address::Proprietary(_, _) => Err(ParseError::UnknownSentence),
+    }
+}
+
+/// Message borrowing its sentence, fields are decoded on access. Positions carry no datum,
+/// see `Parser::datum`.
+#[cfg(feature = "any-sentence")]
+#[derive(Copy, Clone, Debug)]
+pub enum MessageRef<'a> {
+    #[cfg(feature = "gns")]
+    GNS(TalkerId, GNSRef<'a>),
+    #[cfg(feature = "gga")]
+    GGA(TalkerId, GGARef<'a>),
+    #[cfg(feature = "gsa")]
+    GSA(TalkerId, GSARef<'a>),
+    #[cfg(feature = "rmc")]
+    RMC(TalkerId, RMCRef<'a>),
+    #[cfg(feature = "gll")]
+    GLL(TalkerId, GLLRef<'a>),
+    #[cfg(feature = "vtg")]
+    VTG(TalkerId, VTGRef<'a>),
+    #[cfg(feature = "gsv")]
+    GSV(TalkerId, GSVRef<'a>),
+    #[cfg(feature = "zda")]
+    ZDA(TalkerId, ZDARef<'a>),
+    #[cfg(feature = "gst")]
+    GST(TalkerId, GSTRef<'a>),
+    #[cfg(feature = "gbs")]
+    GBS(TalkerId, GBSRef<'a>),
+    #[cfg(feature = "dtm")]
+    DTM(TalkerId, DTMRef<'a>),
+    #[cfg(feature = "grs")]
+    GRS(TalkerId, GRSRef<'a>),
+    #[cfg(feature = "txt")]
+    TXT(TalkerId, TXTRef<'a>),
+    #[cfg(feature = "alm")]
+    ALM(TalkerId, ALMRef<'a>),
+}
 
+#[cfg(feature = "any-sentence")]
+impl<'a> MessageRef<'a> {
+    pub fn talker(&self) -> TalkerId {
+        match *self {
+            #[cfg(feature = "gns")]
+            Self::GNS(talker, _) => talker,
+            #[cfg(feature = "gga")]
+            Self::GGA(talker, _) => talker,
+            #[cfg(feature = "gsa")]
+            Self::GSA(talker, _) => talker,
+            #[cfg(feature = "rmc")]
+            Self::RMC(talker, _) => talker,
+            #[cfg(feature = "gll")]
+            Self::GLL(talker, _) => talker,
+            #[cfg(feature = "vtg")]
+            Self::VTG(talker, _) => talker,
+            #[cfg(feature = "gsv")]
+            Self::GSV(talker, _) => talker,
+            #[cfg(feature = "zda")]
+            Self::ZDA(talker, _) => talker,
+            #[cfg(feature = "gst")]
+            Self::GST(talker, _) => talker,
+            #[cfg(feature = "gbs")]
+            Self::GBS(talker, _) => talker,
+            #[cfg(feature = "dtm")]
+            Self::DTM(talker, _) => talker,
+            #[cfg(feature = "grs")]
+            Self::GRS(talker, _) => talker,
+            #[cfg(feature = "txt")]
+            Self::TXT(talker, _) => talker,
+            #[cfg(feature = "alm")]
+            Self::ALM(talker, _) => talker,
+        }
+    }
+
+    /// Accepts a line without start delimiter and trailing CR LF, only address is validated
+    /// besides checksum
+    pub fn try_from(line: &'a [u8]) -> Result<Self, ParseError> {
+        Self::from_payload(verify_checksum(line)?)
+    }
+
+    /// Accepts address and fields of a sentence whose checksum is already verified
+    pub fn from_payload(payload: &'a [u8]) -> Result<Self, ParseError> {
         let mut splitted = payload.splitn(2, |&b| b == b',');
+        let (talker, formatter) = parse_address(splitted.next().unwrap_or(b""))?;
+        Self::from_fields(talker, formatter, splitted.next())
+    }
 
-        let address = splitted.next().unwrap();
-        let value = match splitted.next() {
-            Some(v) => v,
-            None => return None,
-        };
-
-        match &address[2..] {
-            b"GGA" => Some(Message::GGA(GGA::from(value))),
-            b"GNS" => Some(Message::GNS(GNS::from(value))),
-            b"GSA" => Some(Message::GSA(GSA::from(value))),
-            b"RMC" => Some(Message::RMC(RMC::from(value))),
-            _ => None,
+    /// Accepts fields following the address, none if there is no field at all
+    pub(crate) fn from_fields(
+        talker: TalkerId,
+        formatter: SentenceFormatter,
+        fields: Option<&'a [u8]>,
+    ) -> Result<Self, ParseError> {
+        let value = fields.ok_or(ParseError::MissingField(0))?;
+        Ok(match formatter {
+            #[cfg(feature = "gga")]
+            SentenceFormatter::GGA => Self::GGA(talker, GGARef::from(value)),
+            #[cfg(feature = "gns")]
+            SentenceFormatter::GNS => Self::GNS(talker, GNSRef::from(value)),
+            #[cfg(feature = "gsa")]
+            SentenceFormatter::GSA => Self::GSA(talker, GSARef::from(value)),
+            #[cfg(feature = "rmc")]
+            SentenceFormatter::RMC => Self::RMC(talker, RMCRef::from(value)),
+            #[cfg(feature = "gll")]
+            SentenceFormatter::GLL => Self::GLL(talker, GLLRef::from(value)),
+            #[cfg(feature = "vtg")]
+            SentenceFormatter::VTG => Self::VTG(talker, VTGRef::from(value)),
+            #[cfg(feature = "gsv")]
+            SentenceFormatter::GSV => Self::GSV(talker, GSVRef::from(value)),
+            #[cfg(feature = "zda")]
+            SentenceFormatter::ZDA => Self::ZDA(talker, ZDARef::from(value)),
+            #[cfg(feature = "gst")]
+            SentenceFormatter::GST => Self::GST(talker, GSTRef::from(value)),
+            #[cfg(feature = "gbs")]
+            SentenceFormatter::GBS => Self::GBS(talker, GBSRef::from(value)),
+            #[cfg(feature = "dtm")]
+            SentenceFormatter::DTM => Self::DTM(talker, DTMRef::from(value)),
+            #[cfg(feature = "grs")]
+            SentenceFormatter::GRS => Self::GRS(talker, GRSRef::from(value)),
+            #[cfg(feature = "txt")]
+            SentenceFormatter::TXT => Self::TXT(talker, TXTRef::from(value)),
+            #[cfg(feature = "alm")]
+            SentenceFormatter::ALM => Self::ALM(talker, ALMRef::from(value)),
+        })
+    }
+
+    /// Decodes all fields
+    pub fn decode(&self) -> Result<Message, ParseError> {
+        Ok(match *self {
+            #[cfg(feature = "gga")]
+            Self::GGA(talker, gga) => Message::GGA(talker, gga.decode()?),
+            #[cfg(feature = "gns")]
+            Self::GNS(talker, gns) => Message::GNS(talker, gns.decode()?),
+            #[cfg(feature = "gsa")]
+            Self::GSA(talker, gsa) => Message::GSA(talker, gsa.decode()?),
+            #[cfg(feature = "rmc")]
+            Self::RMC(talker, rmc) => Message::RMC(talker, rmc.decode()?),
+            #[cfg(feature = "gll")]
+            Self::GLL(talker, gll) => Message::GLL(talker, gll.decode()?),
+            #[cfg(feature = "vtg")]
+            Self::VTG(talker, vtg) => Message::VTG(talker, vtg.decode()?),
+            #[cfg(feature = "gsv")]
+            Self::GSV(talker, gsv) => Message::GSV(talker, gsv.decode()?),
+            #[cfg(feature = "zda")]
+            Self::ZDA(talker, zda) => Message::ZDA(talker, zda.decode()?),
+            #[cfg(feature = "gst")]
+            Self::GST(talker, gst) => Message::GST(talker, gst.decode()?),
+            #[cfg(feature = "gbs")]
+            Self::GBS(talker, gbs) => Message::GBS(talker, gbs.decode()?),
+            #[cfg(feature = "dtm")]
+            Self::DTM(talker, dtm) => Message::DTM(talker, dtm.decode()?),
+            #[cfg(feature = "grs")]
+            Self::GRS(talker, grs) => Message::GRS(talker, grs.decode()?),
+            #[cfg(feature = "txt")]
+            Self::TXT(talker, txt) => Message::TXT(talker, txt.decode()?),
+            #[cfg(feature = "alm")]
+            Self::ALM(talker, alm) => Message::ALM(talker, alm.decode()?),
+        })
+    }
+}
+
+/// Returns true if checksum field is two hex digits matching checksum
+pub(crate) fn checksum_matches(field: &[u8], checksum: u8) -> bool {
+    let expected = match field {
+        [a, b] if a.is_ascii_hexdigit() && b.is_ascii_hexdigit() => {
+            from_utf8(field).ok().and_then(|c| u8::from_str_radix(c, 16).ok())
         }
+        _ => None,
+    };
+    expected == Some(checksum)
+}
+
+/// Splits line without start delimiter at checksum delimiter, returns payload if checksum matches
+pub fn verify_checksum(line: &[u8]) -> Result<&[u8], ParseError> {
+    let index = line.iter().rposition(|&b| b == b'*').ok_or(ParseError::Truncated)?;
+    let (payload, checksum) = (&line[..index], &line[index + 1..]);
+    if !checksum_matches(checksum, payload.iter().fold(0, |csum, &b| csum ^ b)) {
+        return Err(ParseError::ChecksumMismatch);
+    }
+    Ok(payload)
+}
+
+struct ChecksumWriter<'a, W: Write> {
+    writer: &'a mut W,
+    checksum: u8,
+}
+
+impl<W: Write> Write for ChecksumWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.checksum = s.bytes().fold(self.checksum, |csum, b| csum ^ b);
+        self.writer.write_str(s)
     }
 }
+
+/// Writes a complete sentence `$<talker><formatter>,<payload>*<checksum>\r\n`
+pub fn write_sentence<W: Write>(
+    writer: &mut W,
+    talker: TalkerId,
+    formatter: &str,
+    payload: impl Display,
+) -> core::fmt::Result {
+    writer.write_char('$')?;
+    let mut checksum_writer = ChecksumWriter { writer, checksum: 0 };
+    write!(checksum_writer, "{}{},{}", talker, formatter, payload)?;
+    let checksum = checksum_writer.checksum;
+    write!(writer, "*{:02X}\r\n", checksum)
+}
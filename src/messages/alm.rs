@@ -0,0 +1,101 @@
+use crate::fields::Fields;
+use crate::message::write_sentence;
+use crate::types::talker::TalkerId;
+
+sentence! {
+    /// GPS almanac, orbit and clock parameters are raw bit patterns as broadcast,
+    /// scaling and sign extension are left to the user
+    #[derive(Clone, Default, Debug, PartialEq)]
+    pub struct ALM / ALMRef {
+        pub num_sentences: u8 = 0 => Fields::parse,
+        /// starts from 1
+        pub sentence_index: u8 = 1 => Fields::parse,
+        pub prn: u8 = 2 => Fields::parse,
+        pub week: u16 = 3 => Fields::parse,
+        pub health: u8 = 4 => Fields::parse_hex,
+        pub eccentricity: u16 = 5 => Fields::parse_hex,
+        /// almanac reference time
+        pub reference_time: u8 = 6 => Fields::parse_hex,
+        /// inclination angle relative to 0.3 semi-circles
+        pub inclination: u16 = 7 => Fields::parse_hex,
+        pub rate_of_right_ascension: u16 = 8 => Fields::parse_hex,
+        pub root_semi_major_axis: u32 = 9 => Fields::parse_hex,
+        pub argument_of_perigee: u32 = 10 => Fields::parse_hex,
+        pub longitude_of_ascending_node: u32 = 11 => Fields::parse_hex,
+        pub mean_anomaly: u32 = 12 => Fields::parse_hex,
+        /// 11 bits
+        pub af0: u16 = 13 => Fields::parse_hex,
+        /// 11 bits
+        pub af1: u16 = 14 => Fields::parse_hex,
+    }
+}
+
+impl ALM {
+    /// Writes this almanac as a complete sentence with checksum
+    pub fn encode<W: core::fmt::Write>(
+        &self,
+        talker: TalkerId,
+        writer: &mut W,
+    ) -> core::fmt::Result {
+        write_sentence(writer, talker, "ALM", self)
+    }
+}
+
+impl core::fmt::Display for ALM {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{},{},{:02},{:04},",
+            self.num_sentences, self.sentence_index, self.prn, self.week
+        )?;
+        write!(f, "{:02X},{:04X},{:02X},", self.health, self.eccentricity, self.reference_time)?;
+        write!(f, "{:04X},{:04X},", self.inclination, self.rate_of_right_ascension)?;
+        write!(f, "{:06X},{:06X},", self.root_semi_major_axis, self.argument_of_perigee)?;
+        write!(f, "{:06X},{:06X},", self.longitude_of_ascending_node, self.mean_anomaly)?;
+        write!(f, "{:03X},{:03X}", self.af0, self.af1)
+    }
+}
+
+mod test {
+    #[test]
+    fn test_alm() {
+        use core::convert::TryFrom;
+
+        use super::{ALMRef, ALM};
+        use crate::error::ParseError;
+        use crate::types::talker::TalkerId;
+
+        let bytes = b"1,1,15,1159,00,441d,4e,16be,fd5e,a10c9f,4a2da4,686e81,58cbe1,0a4,001";
+        let alm = ALM::try_from(&bytes[..]).unwrap();
+        assert_eq!((1, 1), (alm.num_sentences, alm.sentence_index));
+        assert_eq!(15, alm.prn);
+        assert_eq!(1159, alm.week);
+        assert_eq!(0, alm.health);
+        assert_eq!(0x441d, alm.eccentricity);
+        assert_eq!(0x4e, alm.reference_time);
+        assert_eq!(0x16be, alm.inclination);
+        assert_eq!(0xfd5e, alm.rate_of_right_ascension);
+        assert_eq!(0xa10c9f, alm.root_semi_major_axis);
+        assert_eq!(0x4a2da4, alm.argument_of_perigee);
+        assert_eq!(0x686e81, alm.longitude_of_ascending_node);
+        assert_eq!(0x58cbe1, alm.mean_anomaly);
+        assert_eq!((0x0a4, 0x001), (alm.af0, alm.af1));
+
+        let alm = ALMRef::from(&bytes[..]);
+        assert_eq!(Ok(0x686e81), alm.longitude_of_ascending_node());
+        assert_eq!(Ok(0x001), alm.af1());
+        let alm = alm.decode().unwrap();
+
+        let mut sentence = String::new();
+        alm.encode(TalkerId::GP, &mut sentence).unwrap();
+        let expected =
+            "$GPALM,1,1,15,1159,00,441D,4E,16BE,FD5E,A10C9F,4A2DA4,686E81,58CBE1,0A4,001*77\r\n";
+        assert_eq!(expected, sentence);
+
+        let bytes = sentence.as_bytes();
+        assert_eq!(alm, ALM::try_from(&bytes[7..bytes.len() - 5]).unwrap());
+
+        let bytes = b"1,1,15";
+        assert_eq!(Err(ParseError::MissingField(3)), ALM::try_from(&bytes[..]));
+    }
+}
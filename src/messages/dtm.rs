@@ -0,0 +1,74 @@
+use crate::error::ParseError;
+use crate::fields::Fields;
+use crate::types::datum::Datum;
+use crate::types::string::FixedString;
+use crate::types::IntegerDecimal;
+
+/// Maximum length of local datum subdivision code
+pub const MAX_SUBDIVISION_LENGTH: usize = 4;
+
+/// Offset followed by direction, negated for given direction
+fn offset<const NEGATIVE: u8>(fields: &mut Fields) -> Result<IntegerDecimal, ParseError> {
+    let offset: IntegerDecimal = fields.parse()?;
+    Ok(if fields.next()? == [NEGATIVE] { -offset } else { offset })
+}
+
+sentence! {
+    #[derive(Clone, Default, Debug)]
+    pub struct DTM / DTMRef {
+        pub local_datum: Datum = 0 => Fields::parse_from,
+        pub subdivision: Option<FixedString<MAX_SUBDIVISION_LENGTH>> = 1 => Fields::parse_option,
+        /// minutes, northerly positive
+        pub latitude_offset: IntegerDecimal = 2 => offset::<b'S'>,
+        /// minutes, easterly positive
+        pub longitude_offset: IntegerDecimal = 4 => offset::<b'W'>,
+        /// meters
+        pub altitude_offset: IntegerDecimal = 6 => Fields::parse,
+        pub reference_datum: Datum = 7 => Fields::parse_from,
+    }
+}
+
+mod test {
+    #[test]
+    fn test_dtm() {
+        use core::convert::TryFrom;
+
+        use super::{DTMRef, DTM};
+        use crate::error::ParseError;
+        use crate::types::datum::Datum;
+
+        let bytes = b"W84,,0.0,N,0.0,E,0.0,W84";
+        let dtm = DTM::try_from(&bytes[..]).unwrap();
+        assert_eq!(Datum::WGS84, dtm.local_datum);
+        assert!(dtm.subdivision.is_none());
+        assert_eq!(Datum::WGS84, dtm.reference_datum);
+
+        let bytes = b"999,CH,0.08,N,0.07,E,-47.7,W84";
+        let dtm = DTM::try_from(&bytes[..]).unwrap();
+        assert_eq!(Datum::UserDefined, dtm.local_datum);
+        assert_eq!(Some("CH"), dtm.subdivision.as_ref().map(|s| s.as_str()));
+        assert_eq!("0.8#2", format!("{:?}", dtm.latitude_offset));
+        assert_eq!("0.7#2", format!("{:?}", dtm.longitude_offset));
+        assert_eq!(-477, dtm.altitude_offset.real());
+
+        let bytes = b"S85,,0.002,S,0.001,W,0.0,W84";
+        let dtm = DTM::try_from(&bytes[..]).unwrap();
+        assert_eq!(Datum::SGS85, dtm.local_datum);
+        assert_eq!(-2, dtm.latitude_offset.real());
+        assert_eq!(-1, dtm.longitude_offset.real());
+
+        let dtm = DTMRef::from(&bytes[..]);
+        assert_eq!(Ok(Datum::SGS85), dtm.local_datum());
+        assert_eq!(Ok(-1), dtm.longitude_offset().map(|v| v.real()));
+        assert_eq!(Ok(Datum::WGS84), dtm.reference_datum());
+
+        let dtm = DTMRef::from(&b"999,CH,0.08,N,0.07,E,-47.7,W84"[..]);
+        assert_eq!(r#"Ok(Some("CH"))"#, format!("{:?}", dtm.subdivision()));
+
+        let bytes = b"999,ABCDE,0.08,N,0.07,E,-47.7,W84";
+        assert_eq!(Err(ParseError::InvalidField(1)), DTM::try_from(&bytes[..]).map(|_| ()));
+
+        let bytes = b",,,";
+        assert_eq!(Err(ParseError::MissingField(4)), DTM::try_from(&bytes[..]).map(|_| ()));
+    }
+}
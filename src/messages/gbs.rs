@@ -0,0 +1,69 @@
+use crate::fields::Fields;
+use crate::types::system::System;
+use crate::types::time::Time;
+use crate::types::IntegerDecimal;
+
+sentence! {
+    #[derive(Clone, Default, Debug)]
+    pub struct GBS / GBSRef {
+        pub time: Time = 0 => Fields::parse,
+        /// expected error in latitude in meters
+        pub latitude_error: Option<IntegerDecimal> = 1 => Fields::parse_option,
+        /// expected error in longitude in meters
+        pub longitude_error: Option<IntegerDecimal> = 2 => Fields::parse_option,
+        /// expected error in altitude in meters
+        pub altitude_error: Option<IntegerDecimal> = 3 => Fields::parse_option,
+        /// ID of most likely failed satellite
+        pub failed_satellite: Option<u16> = 4 => Fields::parse_option,
+        /// probability of missed detection for most likely failed satellite
+        pub probability: Option<IntegerDecimal> = 5 => Fields::parse_option,
+        /// estimate of bias on most likely failed satellite in meters
+        pub bias: Option<IntegerDecimal> = 6 => Fields::parse_option,
+        /// standard deviation of bias estimate in meters
+        pub bias_stddev: Option<IntegerDecimal> = 7 => Fields::parse_option,
+        /// NMEA 4.10 and later
+        pub system: Option<System> = 8 => Fields::parse_system,
+        /// NMEA 4.10 and later
+        pub signal_id: Option<u8> = 9 => Fields::parse_signal_id,
+    }
+}
+
+mod test {
+    #[test]
+    fn test_gbs() {
+        use core::convert::TryFrom;
+
+        use super::{GBSRef, GBS};
+        use crate::error::ParseError;
+        use crate::types::system::System;
+
+        let bytes = b"235458.00,1.4,1.3,3.1,03,,-21.4,3.8,1,0";
+        let gbs = GBS::try_from(&bytes[..]).unwrap();
+        assert_eq!("23:54:58.00", format!("{:?}", gbs.time));
+        assert_eq!("Some(1.4#1)", format!("{:?}", gbs.latitude_error));
+        assert_eq!("Some(1.3#1)", format!("{:?}", gbs.longitude_error));
+        assert_eq!("Some(3.1#1)", format!("{:?}", gbs.altitude_error));
+        assert_eq!(Some(3), gbs.failed_satellite);
+        assert!(gbs.probability.is_none());
+        assert_eq!(Some(-214), gbs.bias.map(|v| v.real()));
+        assert_eq!("Some(3.8#1)", format!("{:?}", gbs.bias_stddev));
+        assert_eq!(Some(System::GPS), gbs.system);
+        assert_eq!(Some(0), gbs.signal_id);
+
+        let bytes = b"235503.00,1.6,1.4,3.2,,,,";
+        let gbs = GBS::try_from(&bytes[..]).unwrap();
+        assert!(gbs.failed_satellite.is_none());
+        assert!(gbs.bias.is_none());
+        assert!(gbs.system.is_none());
+        assert!(gbs.signal_id.is_none());
+
+        let bytes = b"235503.00,,,,,,,";
+        let gbs = GBS::try_from(&bytes[..]).unwrap();
+        assert!(gbs.latitude_error.is_none() && gbs.longitude_error.is_none());
+        assert!(gbs.altitude_error.is_none());
+        assert_eq!(Ok(None), GBSRef::from(&bytes[..]).altitude_error());
+
+        let bytes = b",,,,";
+        assert_eq!(Err(ParseError::MissingField(5)), GBS::try_from(&bytes[..]).map(|_| ()));
+    }
+}
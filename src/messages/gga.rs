@@ -1,61 +1,119 @@
-use core::str::from_utf8_unchecked as utf8;
-
+use crate::error::ParseError;
+use crate::fields::Fields;
+use crate::types::datum::Datum;
 use crate::types::latitude::Latitude;
 use crate::types::longitude::Longitude;
 use crate::types::time::Time;
-use crate::types::{IntegerDecimal, Quality};
-
-#[derive(Clone, Default, Debug)]
-pub struct GGA {
-    pub time: Time,
-    pub latitude: Latitude,
-    pub longitude: Longitude,
-    pub quality: Quality,
-    pub num_satellites: u8,
-    pub hdop: IntegerDecimal,
-    /// meters
-    pub altitude: IntegerDecimal,
+use crate::types::{IntegerDecimal, Quality, MAX_DECIMAL_LENGTH, MAX_INTEGER_DECIMAL};
+
+/// Converts value in feet to meters if unit is `F`
+fn to_meters(value: IntegerDecimal, unit: &[u8]) -> IntegerDecimal {
+    if unit != b"F" {
+        return value;
+    }
+    let meters = value.real() as i64 * 3048;
+    let decimal_length = value.decimal_length();
+    if decimal_length < MAX_DECIMAL_LENGTH && meters.abs() / 1000 <= MAX_INTEGER_DECIMAL as i64 {
+        return IntegerDecimal::new((meters / 1000) as i32, decimal_length + 1);
+    }
+    IntegerDecimal::new((meters / 10000) as i32, decimal_length)
+}
+
+/// Value followed by unit, in meters
+fn meters(fields: &mut Fields) -> Result<Option<IntegerDecimal>, ParseError> {
+    let value: Option<IntegerDecimal> = fields.parse_option()?;
+    let unit = fields.next()?;
+    Ok(value.map(|value| to_meters(value, unit)))
+}
+
+/// Value followed by unit, in meters, either may be absent
+fn trailing_meters(fields: &mut Fields) -> Result<Option<IntegerDecimal>, ParseError> {
+    let value: Option<IntegerDecimal> = fields.parse_trailing()?;
+    let unit = fields.next_or_empty();
+    Ok(value.map(|value| to_meters(value, unit)))
 }
 
-impl From<&[u8]> for GGA {
-    fn from(bytes: &[u8]) -> Self {
-        if bytes.iter().fold(0, |sum, &b| sum + (b == b',') as usize) < 9 {
-            return Self::default();
-        }
-        let mut fields = bytes.split(|&b| b == b',');
-        let time = Time::from(fields.next().unwrap());
-        let mut latitude = Latitude::from(fields.next().unwrap());
-        if fields.next().unwrap() == b"S" {
-            latitude.0 = -latitude.0;
-        }
-        let mut longitude = Longitude::from(fields.next().unwrap());
-        if fields.next().unwrap() == b"W" {
-            longitude.0 = -longitude.0;
-        }
-        let quality = Quality::from(fields.next().unwrap());
-        let num_satellites: u8 = unsafe { utf8(fields.next().unwrap()) }.parse().unwrap_or(0);
-        let hdop: IntegerDecimal = fields.next().unwrap().into();
-        let altitude: IntegerDecimal = fields.next().unwrap().into();
-        Self { time, latitude, longitude, quality, num_satellites, hdop, altitude }
+sentence! {
+    #[derive(Clone, Default, Debug)]
+    pub struct GGA / GGARef {
+        pub time: Option<Time> = 0 => Fields::parse_option,
+        pub latitude: Option<Latitude> = 1 => Fields::parse_latitude,
+        pub longitude: Option<Longitude> = 3 => Fields::parse_longitude,
+        pub quality: Option<Quality> = 5 => Fields::parse_option,
+        pub num_satellites: Option<u8> = 6 => Fields::parse_option,
+        pub hdop: Option<IntegerDecimal> = 7 => Fields::parse_option,
+        /// meters
+        pub altitude: Option<IntegerDecimal> = 8 => meters,
+        /// height of geoid above WGS84 ellipsoid in meters
+        pub geoid_separation: Option<IntegerDecimal> = 10 => trailing_meters,
+        /// age of differential corrections in seconds
+        pub dgps_age: Option<IntegerDecimal> = 12 => Fields::parse_trailing,
+        pub dgps_station_id: Option<u16> = 13 => Fields::parse_trailing,
+    }
+    extra {
+        /// WGS84 unless announced otherwise by DTM of the same talker
+        pub datum: Datum,
     }
 }
 
 mod test {
     #[test]
     fn test_gga() {
-        use super::GGA;
+        use core::convert::TryFrom;
+
+        use super::{GGARef, GGA};
+        use crate::error::ParseError;
 
         let bytes = b"092725.00,4717.11399,N,00833.91590,E,1,08,1.01,499.6,M,48.0,M,,";
-        let gga = GGA::from(&bytes[..]);
-        assert_eq!("09:27:25.00", format!("{:?}", gga.time));
-        assert_eq!(r#"N47°17'11"399"#, format!("{:?}", gga.latitude));
-        assert_eq!(r#"E008°33'91"590"#, format!("{:?}", gga.longitude));
-        assert_eq!("Autonomous", format!("{:?}", gga.quality));
-        assert_eq!(8, gga.num_satellites);
-        assert_eq!("1.1#2", format!("{:?}", gga.hdop));
-        assert_eq!("499.6#1", format!("{:?}", gga.altitude));
+        let gga = GGA::try_from(&bytes[..]).unwrap();
+        assert_eq!("Some(09:27:25.00)", format!("{:?}", gga.time));
+        assert_eq!(r#"Some(N47°17'11"399)"#, format!("{:?}", gga.latitude));
+        assert_eq!(r#"Some(E008°33'91"590)"#, format!("{:?}", gga.longitude));
+        assert_eq!("Some(Autonomous)", format!("{:?}", gga.quality));
+        assert_eq!(Some(8), gga.num_satellites);
+        assert_eq!("Some(1.1#2)", format!("{:?}", gga.hdop));
+        assert_eq!("Some(499.6#1)", format!("{:?}", gga.altitude));
+        assert_eq!("Some(48.0#1)", format!("{:?}", gga.geoid_separation));
+        assert!(gga.dgps_age.is_none());
+        assert!(gga.dgps_station_id.is_none());
+
+        let bytes = b"092725.00,4717.11399,N,00833.91590,E,4,12,0.7,1638.8,F,157.5,F,1.2,0031";
+        let gga = GGA::try_from(&bytes[..]).unwrap();
+        assert_eq!("Some(499.50#2)", format!("{:?}", gga.altitude));
+        assert_eq!("Some(48.0#2)", format!("{:?}", gga.geoid_separation));
+        assert_eq!("Some(1.2#1)", format!("{:?}", gga.dgps_age));
+        assert_eq!(Some(31), gga.dgps_station_id);
 
         let bytes = b"092725.00,,,,,,,,,,,,,";
-        let _ = GGA::from(&bytes[..]);
+        let gga = GGA::try_from(&bytes[..]).unwrap();
+        assert!(gga.latitude.is_none() && gga.longitude.is_none());
+        assert!(gga.quality.is_none());
+        assert_eq!(Ok(None), GGARef::from(&bytes[..]).quality());
+        let precise = GGARef::from(&b"092725.00,,,,,3,,,,,,,,"[..]);
+        assert_eq!("Ok(Some(Precise))", format!("{:?}", precise.quality()));
+        let invalid = GGARef::from(&b"092725.00,,,,,X,,,,,,,,"[..]);
+        assert_eq!(Err(ParseError::InvalidField(5)), invalid.quality());
+        assert!(gga.num_satellites.is_none() && gga.hdop.is_none());
+        assert!(gga.altitude.is_none() && gga.geoid_separation.is_none());
+
+        let bytes = b"092725.00,4717.11399,N,00833.91590,E,1,08,1.01,0.0,M,,M,,";
+        let gga = GGA::try_from(&bytes[..]).unwrap();
+        assert_eq!(Some(0), gga.altitude.map(|altitude| altitude.real()));
+        assert!(gga.geoid_separation.is_none());
+
+        let bytes = b"092725.00,4717.11399,S,00833.91590,W,4,12,0.7,1638.8,F,157.5,F,1.2,0031";
+        let gga = GGARef::from(&bytes[..]);
+        assert_eq!(Ok(Some(-471711399)), gga.latitude().map(|l| l.map(|l| l.0)));
+        assert_eq!(Ok(Some(-83391590)), gga.longitude().map(|l| l.map(|l| l.0)));
+        assert_eq!("Ok(Some(499.50#2))", format!("{:?}", gga.altitude()));
+        assert_eq!(Ok(Some(31)), gga.dgps_station_id());
+        assert_eq!(Ok(None), GGARef::from(&b"092725.00,,,,,,,,,"[..]).dgps_station_id());
+        assert_eq!(
+            Err(ParseError::MissingField(9)),
+            GGARef::from(&b"0,,,,,,,,1.0"[..]).altitude().map(|_| ())
+        );
+
+        let bytes = b"092725.00,47x7.11399,N,00833.91590,E,1,08,1.01,499.6,M,48.0,M,,";
+        assert_eq!(Err(ParseError::InvalidField(1)), GGA::try_from(&bytes[..]).map(|_| ()));
     }
 }
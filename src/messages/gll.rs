@@ -0,0 +1,59 @@
+use crate::error::ParseError;
+use crate::fields::Fields;
+use crate::types::datum::Datum;
+use crate::types::latitude::Latitude;
+use crate::types::longitude::Longitude;
+use crate::types::position_mode::PositionMode;
+use crate::types::time::Time;
+use crate::types::Status;
+
+fn latitude(fields: &mut Fields) -> Result<Latitude, ParseError> {
+    Ok(fields.parse_latitude()?.unwrap_or_default())
+}
+
+fn longitude(fields: &mut Fields) -> Result<Longitude, ParseError> {
+    Ok(fields.parse_longitude()?.unwrap_or_default())
+}
+
+sentence! {
+    #[derive(Clone, Default, Debug)]
+    pub struct GLL / GLLRef {
+        pub latitude: Latitude = 0 => latitude,
+        pub longitude: Longitude = 2 => longitude,
+        pub time: Time = 4 => Fields::parse,
+        pub status: Status = 5 => Fields::parse_from,
+        /// NMEA 2.3 and later
+        pub position_mode: PositionMode = 6 => Fields::parse_trailing_from,
+    }
+    extra {
+        /// WGS84 unless announced otherwise by DTM of the same talker
+        pub datum: Datum,
+    }
+}
+
+mod test {
+    #[test]
+    fn test_gll() {
+        use core::convert::TryFrom;
+
+        use super::GLL;
+
+        let bytes = b"4717.11364,N,00833.91565,E,092321.00,A,A";
+        let gll = GLL::try_from(&bytes[..]).unwrap();
+        assert_eq!(r#"N47°17'11"364"#, format!("{:?}", gll.latitude));
+        assert_eq!(r#"E008°33'91"565"#, format!("{:?}", gll.longitude));
+        assert_eq!("09:23:21.00", format!("{:?}", gll.time));
+        assert!(gll.status.0);
+        assert_eq!("Autonomous", format!("{:?}", gll.position_mode));
+
+        let bytes = b"4717.11364,S,00833.91565,W,092321.00,V";
+        let gll = GLL::try_from(&bytes[..]).unwrap();
+        assert!(gll.latitude.is_south());
+        assert!(gll.longitude.is_west());
+        assert!(!gll.status.0);
+        assert_eq!("NoFix", format!("{:?}", gll.position_mode));
+
+        let bytes = b",,,,,";
+        assert!(GLL::try_from(&bytes[..]).is_ok());
+    }
+}
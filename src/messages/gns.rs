@@ -1,65 +1,137 @@
-use core::str::from_utf8_unchecked as utf8;
-
+use crate::error::ParseError;
+use crate::fields::{Fields, FromField};
+use crate::types::datum::Datum;
 use crate::types::latitude::Latitude;
 use crate::types::longitude::Longitude;
 use crate::types::position_mode::PositionMode;
+use crate::types::system::System;
 use crate::types::time::Time;
-use crate::types::IntegerDecimal;
-
-#[derive(Clone, Default, Debug)]
-pub struct GNS {
-    pub time: Time,
-    pub latitude: Latitude,
-    pub longitude: Longitude,
-    pub position_modes: [PositionMode; 4],
-    pub num_satellites: u8,
-    pub hdop: IntegerDecimal,
-    pub altitude: IntegerDecimal,
-}
+use crate::types::{IntegerDecimal, NavigationalStatus};
 
-impl From<&[u8]> for GNS {
-    fn from(bytes: &[u8]) -> Self {
-        if bytes.iter().fold(0, |sum, &b| sum + (b == b',') as usize) < 9 {
-            return Self::default();
-        }
-        let mut fields = bytes.split(|&b| b == b',');
-        let time = Time::from(fields.next().unwrap());
-        let mut latitude = Latitude::from(fields.next().unwrap());
-        if fields.next().unwrap() == b"S" {
-            latitude.0 = -latitude.0;
-        }
-        let mut longitude = Longitude::from(fields.next().unwrap());
-        if fields.next().unwrap() == b"W" {
-            longitude.0 = -longitude.0;
-        }
-        let field = fields.next().unwrap();
-        let mut position_modes = [PositionMode::default(); 4];
-        for i in 0..field.len() {
-            position_modes[i] = PositionMode::from(&field[i..i + 1]);
+/// Mode characters are ordered GPS, GLONASS, Galileo, BeiDou, QZSS and NavIC
+const NUM_SYSTEMS: usize = 6;
+
+/// Mode characters indexed by system ID - 1, none if not reported
+struct PositionModes([Option<PositionMode>; NUM_SYSTEMS]);
+
+impl FromField for PositionModes {
+    fn from_field(field: &[u8]) -> Option<Self> {
+        let mut position_modes = [None; NUM_SYSTEMS];
+        for (i, mode) in field.iter().take(NUM_SYSTEMS).enumerate() {
+            position_modes[i] = Some(PositionMode::try_from(core::slice::from_ref(mode))?);
         }
-        let num_satellites: u8 = unsafe { utf8(fields.next().unwrap()) }.parse().unwrap_or(0);
-        let hdop: IntegerDecimal = fields.next().unwrap().into();
-        let altitude: IntegerDecimal = fields.next().unwrap().into();
-        Self { time, latitude, longitude, position_modes, num_satellites, hdop, altitude }
+        Some(Self(position_modes))
+    }
+}
+
+fn position_modes(fields: &mut Fields) -> Result<[Option<PositionMode>; NUM_SYSTEMS], ParseError> {
+    Ok(fields.parse_option()?.map_or([None; NUM_SYSTEMS], |PositionModes(modes)| modes))
+}
+
+sentence! {
+    #[derive(Clone, Default, Debug)]
+    pub struct GNS / GNSRef {
+        pub time: Option<Time> = 0 => Fields::parse_option,
+        pub latitude: Option<Latitude> = 1 => Fields::parse_latitude,
+        pub longitude: Option<Longitude> = 3 => Fields::parse_longitude,
+        /// indexed by system ID - 1, none if not reported
+        pub position_modes: [Option<PositionMode>; NUM_SYSTEMS] = 5 => position_modes,
+        pub num_satellites: Option<u8> = 6 => Fields::parse_option,
+        pub hdop: Option<IntegerDecimal> = 7 => Fields::parse_option,
+        /// meters
+        pub altitude: Option<IntegerDecimal> = 8 => Fields::parse_option,
+        /// height of geoid above WGS84 ellipsoid in meters
+        pub geoid_separation: Option<IntegerDecimal> = 9 => Fields::parse_option,
+        /// age of differential corrections in seconds
+        pub dgps_age: Option<IntegerDecimal> = 10 => Fields::parse_trailing,
+        pub dgps_station_id: Option<u16> = 11 => Fields::parse_trailing,
+        /// NMEA 4.10 and later
+        pub navigational_status: Option<NavigationalStatus> = 12 => Fields::parse_trailing,
+    }
+    extra {
+        /// WGS84 unless announced otherwise by DTM of the same talker
+        pub datum: Datum,
+    }
+}
+
+impl GNS {
+    pub fn position_mode(&self, system: System) -> Option<PositionMode> {
+        self.position_modes[system.id() as usize - 1]
+    }
+}
+
+impl<'a> GNSRef<'a> {
+    pub fn position_mode(&self, system: System) -> Result<Option<PositionMode>, ParseError> {
+        Ok(self.position_modes()?[system.id() as usize - 1])
     }
 }
 
 mod test {
     #[test]
     fn test_gns() {
-        use super::GNS;
+        use core::convert::TryFrom;
+
+        use super::{GNSRef, GNS};
+        use crate::error::ParseError;
+        use crate::message::Message;
+        use crate::types::system::System;
 
         let bytes = b"103600.01,5114.51176,N,00012.29380,W,ANNN,07,1.18,111.5,45.6,,,V";
-        let gns = GNS::from(&bytes[..]);
-        assert_eq!("10:36:00.01", format!("{:?}", gns.time));
-        assert_eq!(r#"N51°14'51"176"#, format!("{:?}", gns.latitude));
-        assert_eq!(r#"W000°12'29"380"#, format!("{:?}", gns.longitude));
-        assert_eq!("[Autonomous, NoFix, NoFix, NoFix]", format!("{:?}", gns.position_modes));
-        assert_eq!(7, gns.num_satellites);
-        assert_eq!("1.18#2", format!("{:?}", gns.hdop));
-        assert_eq!("111.5#1", format!("{:?}", gns.altitude));
+        let gns = GNS::try_from(&bytes[..]).unwrap();
+        assert_eq!("Some(10:36:00.01)", format!("{:?}", gns.time));
+        assert_eq!(r#"Some(N51°14'51"176)"#, format!("{:?}", gns.latitude));
+        assert_eq!(r#"Some(W000°12'29"380)"#, format!("{:?}", gns.longitude));
+        assert_eq!(
+            "[Some(Autonomous), Some(NoFix), Some(NoFix), Some(NoFix), None, None]",
+            format!("{:?}", gns.position_modes)
+        );
+        assert_eq!(Some(7), gns.num_satellites);
+        assert_eq!("Some(1.18#2)", format!("{:?}", gns.hdop));
+        assert_eq!("Some(111.5#1)", format!("{:?}", gns.altitude));
+        assert_eq!("Some(45.6#1)", format!("{:?}", gns.geoid_separation));
+        assert!(gns.dgps_age.is_none());
+        assert!(gns.dgps_station_id.is_none());
+        assert_eq!("Some(NotValid)", format!("{:?}", gns.navigational_status));
+
+        let bytes = b"103600.01,5114.51176,N,00012.29380,W,DRFAEN,20,0.6,111.5,45.6,2.0,0120,S";
+        let gns = GNS::try_from(&bytes[..]).unwrap();
+        assert_eq!("Some(Differential)", format!("{:?}", gns.position_mode(System::GPS)));
+        assert_eq!("Some(Estimated)", format!("{:?}", gns.position_mode(System::QZSS)));
+        assert_eq!("Some(NoFix)", format!("{:?}", gns.position_mode(System::NavIC)));
+        assert_eq!("Some(2.0#1)", format!("{:?}", gns.dgps_age));
+        assert_eq!(Some(120), gns.dgps_station_id);
+        assert_eq!("Some(Safe)", format!("{:?}", gns.navigational_status));
+
+        let gns = GNSRef::from(&bytes[..]);
+        assert_eq!("Ok(Some(Estimated))", format!("{:?}", gns.position_mode(System::QZSS)));
+        assert_eq!(Ok(Some(120)), gns.dgps_station_id());
+        assert_eq!("Ok(Some(Safe))", format!("{:?}", gns.navigational_status()));
+
+        // mode characters are positional whatever the talker
+        let bytes = b"GLGNS,103600.01,5114.51176,N,00012.29380,W,NA,07,1.18,111.5,45.6,,,V*02";
+        #[allow(unreachable_patterns)]
+        match Message::try_from(&bytes[..]).unwrap() {
+            Message::GNS(_, gns) => {
+                assert_eq!("Some(NoFix)", format!("{:?}", gns.position_mode(System::GPS)));
+                let glonass = gns.position_mode(System::GLONASS);
+                assert_eq!("Some(Autonomous)", format!("{:?}", glonass));
+            }
+            _ => panic!(),
+        }
+
+        let bytes = b"103600.01,,,,,ANNNNNNN,,,,,,,V";
+        let gns = GNS::try_from(&bytes[..]).unwrap();
+        assert!(gns.position_modes.iter().all(|mode| mode.is_some()));
 
         let bytes = b"103600.01,,,,,,,,,,,,V";
-        let _ = GNS::from(&bytes[..]);
+        assert!(GNS::try_from(&bytes[..]).is_ok());
+
+        let bytes = b"103600.01,,,,,AX,,,,,,,V";
+        assert_eq!(Err(ParseError::InvalidField(5)), GNS::try_from(&bytes[..]).map(|_| ()));
+        let bytes = b"103600.01,,,,,A,,,,,,,X";
+        assert_eq!(
+            Err(ParseError::InvalidField(12)),
+            GNSRef::from(&bytes[..]).navigational_status()
+        );
     }
 }
@@ -0,0 +1,95 @@
+use crate::error::ParseError;
+use crate::fields::Fields;
+use crate::types::system::System;
+use crate::types::time::Time;
+use crate::types::IntegerDecimal;
+
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub enum ResidualMode {
+    /// residuals were used to calculate position given in matching GGA or GNS
+    #[default]
+    Used,
+    /// residuals were recomputed after GGA or GNS position was computed
+    Recomputed,
+}
+
+impl From<&[u8]> for ResidualMode {
+    fn from(bytes: &[u8]) -> Self {
+        match bytes.first().copied().unwrap_or(b'0') {
+            b'1' => Self::Recomputed,
+            _ => Self::Used,
+        }
+    }
+}
+
+/// Index of first residual
+const RESIDUALS: usize = 2;
+
+fn residuals(fields: &mut Fields) -> Result<[Option<IntegerDecimal>; 12], ParseError> {
+    let mut residuals = [None; 12];
+    for residual in residuals.iter_mut() {
+        *residual = fields.parse_option()?;
+    }
+    Ok(residuals)
+}
+
+sentence! {
+    #[derive(Clone, Default, Debug)]
+    pub struct GRS / GRSRef {
+        pub time: Time = 0 => Fields::parse,
+        pub mode: ResidualMode = 1 => Fields::parse_from,
+        /// meters, in same order as satellites in GSA
+        pub residuals: [Option<IntegerDecimal>; 12] = RESIDUALS => residuals,
+        /// NMEA 4.10 and later
+        pub system: Option<System> = 14 => Fields::parse_system,
+        /// NMEA 4.10 and later
+        pub signal_id: Option<u8> = 15 => Fields::parse_signal_id,
+    }
+}
+
+impl<'a> GRSRef<'a> {
+    /// meters, index in same order as satellites in GSA, none if index exceeds 11
+    pub fn residual(&self, index: usize) -> Result<Option<IntegerDecimal>, ParseError> {
+        if index >= 12 {
+            return Ok(None);
+        }
+        Fields::at(self.0, RESIDUALS + index).parse_option()
+    }
+}
+
+mod test {
+    #[test]
+    fn test_grs() {
+        use core::convert::TryFrom;
+
+        use super::{GRSRef, ResidualMode, GRS};
+        use crate::error::ParseError;
+        use crate::types::system::System;
+
+        let bytes = b"082632.00,1,0.54,0.83,1.00,1.02,-2.12,2.64,-0.71,-1.18,0.25,,,,1,0";
+        let grs = GRS::try_from(&bytes[..]).unwrap();
+        assert_eq!("08:26:32.00", format!("{:?}", grs.time));
+        assert_eq!(ResidualMode::Recomputed, grs.mode);
+        assert_eq!("Some(0.54#2)", format!("{:?}", grs.residuals[0]));
+        assert_eq!(Some(-212), grs.residuals[4].map(|v| v.real()));
+        assert_eq!(Some(-71), grs.residuals[6].map(|v| v.real()));
+        assert_eq!(9, grs.residuals.iter().filter(|r| r.is_some()).count());
+        assert_eq!(Some(System::GPS), grs.system);
+        assert_eq!(Some(0), grs.signal_id);
+
+        let grs = GRSRef::from(&bytes[..]);
+        assert_eq!(Ok(ResidualMode::Recomputed), grs.mode());
+        assert_eq!(Ok(Some(-212)), grs.residual(4).map(|r| r.map(|r| r.real())));
+        assert_eq!(Ok(None), grs.residual(12));
+        assert_eq!(Ok(Some(System::GPS)), grs.system());
+
+        let bytes = b"082632.00,0,0.54,0.83,,,,,,,,,,";
+        let grs = GRS::try_from(&bytes[..]).unwrap();
+        assert_eq!(ResidualMode::Used, grs.mode);
+        assert!(grs.residuals[2].is_none());
+        assert!(grs.system.is_none());
+
+        let bytes = b",,,";
+        assert_eq!(Err(ParseError::MissingField(4)), GRS::try_from(&bytes[..]).map(|_| ()));
+    }
+}
@@ -1,31 +1,111 @@
-use crate::types::{NavigationMode, OperationMode};
+use crate::error::ParseError;
+use crate::fields::Fields;
+use crate::types::system::System;
+use crate::types::{IntegerDecimal, NavigationMode, OperationMode};
 
-#[derive(Clone, Default, Debug)]
-pub struct GSA {
-    pub operation_mode: OperationMode,
-    pub navigation_mode: NavigationMode,
+/// Index of first satellite ID
+const SATELLITES: usize = 2;
+
+sentence! {
+    #[derive(Clone, Default, Debug)]
+    pub struct GSA / GSARef {
+        pub operation_mode: Option<OperationMode> = 0 => Fields::parse_option,
+        pub navigation_mode: Option<NavigationMode> = 1 => Fields::parse_option,
+        pub pdop: Option<IntegerDecimal> = 14 => Fields::parse_option,
+        pub hdop: Option<IntegerDecimal> = 15 => Fields::parse_option,
+        pub vdop: Option<IntegerDecimal> = 16 => Fields::parse_option,
+        /// NMEA 4.10 and later
+        pub system: Option<System> = 17 => Fields::parse_system,
+    }
+    extra satellites {
+        /// IDs of satellites used in solution
+        pub satellites: [u16; 12],
+        /// number of valid entries in satellites
+        pub num_satellites: u8,
+    }
 }
 
-impl From<&[u8]> for GSA {
-    fn from(bytes: &[u8]) -> Self {
-        if bytes.iter().fold(0, |sum, &b| sum + (b == b',') as usize) < 1 {
-            return Self::default();
+/// Non-empty satellite IDs and their number
+fn satellites(fields: &mut Fields) -> Result<([u16; 12], u8), ParseError> {
+    fields.seek(SATELLITES);
+    let mut satellites = [0u16; 12];
+    let mut num_satellites = 0;
+    for _ in 0..12 {
+        if let Some(id) = fields.parse_option()? {
+            satellites[num_satellites] = id;
+            num_satellites += 1;
         }
-        let mut fields = bytes.split(|&b| b == b',');
-        let operation_mode = OperationMode::from(fields.next().unwrap());
-        let navigation_mode = NavigationMode::from(fields.next().unwrap());
-        Self { operation_mode, navigation_mode }
+    }
+    Ok((satellites, num_satellites as u8))
+}
+
+impl GSA {
+    pub fn satellites(&self) -> &[u16] {
+        &self.satellites[..self.num_satellites as usize]
+    }
+}
+
+impl<'a> GSARef<'a> {
+    /// IDs of satellites used in solution, empty fields skipped, ends after first error
+    pub fn satellites(&self) -> impl Iterator<Item = Result<u16, ParseError>> + 'a {
+        let mut fields = Fields::at(self.0, SATELLITES);
+        let mut failed = false;
+        (0..12).filter_map(move |_| {
+            if failed {
+                return None;
+            }
+            let result = fields.parse_option().transpose();
+            failed = matches!(result, Some(Err(_)));
+            result
+        })
     }
 }
 
 mod test {
     #[test]
     fn test_gsa() {
-        use super::GSA;
+        use core::convert::TryFrom;
+
+        use super::{GSARef, GSA};
+        use crate::error::ParseError;
+        use crate::types::system::System;
 
         let bytes = b"A,3,23,29,07,08,09,18,26,28,,,,,1.94,1.18,1.54,1";
-        let gsa = GSA::from(&bytes[..]);
-        assert_eq!("Auto", format!("{:?}", gsa.operation_mode));
-        assert_eq!("_3DFix", format!("{:?}", gsa.navigation_mode));
+        let gsa = GSA::try_from(&bytes[..]).unwrap();
+        assert_eq!("Some(Auto)", format!("{:?}", gsa.operation_mode));
+        assert_eq!("Some(_3DFix)", format!("{:?}", gsa.navigation_mode));
+        assert_eq!(&[23, 29, 7, 8, 9, 18, 26, 28], gsa.satellites());
+        assert_eq!("Some(1.94#2)", format!("{:?}", gsa.pdop));
+        assert_eq!("Some(1.18#2)", format!("{:?}", gsa.hdop));
+        assert_eq!("Some(1.54#2)", format!("{:?}", gsa.vdop));
+        assert_eq!(Some(System::GPS), gsa.system);
+
+        let gsa = GSARef::from(&bytes[..]);
+        let satellites: Result<Vec<u16>, _> = gsa.satellites().collect();
+        assert_eq!(Ok(vec![23, 29, 7, 8, 9, 18, 26, 28]), satellites);
+        assert_eq!("Ok(Some(1.54#2))", format!("{:?}", gsa.vdop()));
+        assert_eq!(Ok(Some(System::GPS)), gsa.system());
+
+        let bytes = b"M,2,65,66,,,,,,,,,,,2.5,1.3,2.1";
+        let gsa = GSA::try_from(&bytes[..]).unwrap();
+        assert_eq!("Some(Manual)", format!("{:?}", gsa.operation_mode));
+        assert_eq!(&[65, 66], gsa.satellites());
+        assert_eq!(None, gsa.system);
+
+        let bytes = b",,,,,,,,,,,,,,,,";
+        let gsa = GSA::try_from(&bytes[..]).unwrap();
+        assert!(gsa.operation_mode.is_none() && gsa.navigation_mode.is_none());
+        let gsa = GSARef::from(&bytes[..]);
+        assert_eq!(Ok(None), gsa.operation_mode());
+        assert_eq!(Ok(None), gsa.navigation_mode());
+        let bytes = b"A,4,,,,,,,,,,,,,,,";
+        assert_eq!(Err(ParseError::InvalidField(1)), GSA::try_from(&bytes[..]).map(|_| ()));
+
+        let bytes = b"A,1,5,x";
+        let satellites: Vec<_> = GSARef::from(&bytes[..]).satellites().collect();
+        assert_eq!(vec![Ok(5), Err(ParseError::InvalidField(3))], satellites);
+
+        let bytes = b"A,1";
+        assert_eq!(Err(ParseError::MissingField(2)), GSA::try_from(&bytes[..]).map(|_| ()));
     }
 }
@@ -0,0 +1,63 @@
+use crate::fields::Fields;
+use crate::types::time::Time;
+use crate::types::IntegerDecimal;
+
+sentence! {
+    #[derive(Clone, Default, Debug)]
+    pub struct GST / GSTRef {
+        pub time: Time = 0 => Fields::parse,
+        /// RMS value of standard deviation of ranges
+        pub rms: Option<IntegerDecimal> = 1 => Fields::parse_option,
+        /// standard deviation of semi-major axis of error ellipse in meters
+        pub semi_major: Option<IntegerDecimal> = 2 => Fields::parse_option,
+        /// standard deviation of semi-minor axis of error ellipse in meters
+        pub semi_minor: Option<IntegerDecimal> = 3 => Fields::parse_option,
+        /// orientation of semi-major axis of error ellipse in degrees from true north
+        pub orientation: Option<IntegerDecimal> = 4 => Fields::parse_option,
+        /// standard deviation of latitude error in meters
+        pub latitude_error: Option<IntegerDecimal> = 5 => Fields::parse_option,
+        /// standard deviation of longitude error in meters
+        pub longitude_error: Option<IntegerDecimal> = 6 => Fields::parse_option,
+        /// standard deviation of altitude error in meters
+        pub altitude_error: Option<IntegerDecimal> = 7 => Fields::parse_option,
+    }
+}
+
+mod test {
+    #[test]
+    fn test_gst() {
+        use core::convert::TryFrom;
+
+        use super::{GSTRef, GST};
+        use crate::error::ParseError;
+
+        let bytes = b"082356.00,1.8,,,,1.7,1.3,2.2";
+        let gst = GST::try_from(&bytes[..]).unwrap();
+        assert_eq!("08:23:56.00", format!("{:?}", gst.time));
+        assert_eq!("Some(1.8#1)", format!("{:?}", gst.rms));
+        assert!(gst.semi_major.is_none());
+        assert!(gst.semi_minor.is_none());
+        assert!(gst.orientation.is_none());
+        assert_eq!("Some(1.7#1)", format!("{:?}", gst.latitude_error));
+        assert_eq!("Some(1.3#1)", format!("{:?}", gst.longitude_error));
+        assert_eq!("Some(2.2#1)", format!("{:?}", gst.altitude_error));
+
+        let bytes = b"172814.00,0.006,0.023,0.020,273.6,0.023,0.020,0.031";
+        let gst = GST::try_from(&bytes[..]).unwrap();
+        assert_eq!("Some(0.23#3)", format!("{:?}", gst.semi_major));
+        assert_eq!("Some(273.6#1)", format!("{:?}", gst.orientation));
+        assert_eq!(Some(31), gst.altitude_error.map(|v| v.real()));
+
+        // receiver without fix
+        let bytes = b"082356.00,,,,,,,";
+        let gst = GST::try_from(&bytes[..]).unwrap();
+        assert!(gst.rms.is_none() && gst.latitude_error.is_none());
+        assert!(gst.longitude_error.is_none() && gst.altitude_error.is_none());
+        let gst = GSTRef::from(&bytes[..]);
+        assert_eq!(Ok(None), gst.rms());
+        assert_eq!(Ok(None), gst.altitude_error());
+
+        let bytes = b",,,,";
+        assert_eq!(Err(ParseError::MissingField(5)), GST::try_from(&bytes[..]).map(|_| ()));
+    }
+}
@@ -0,0 +1,340 @@
+use crate::error::ParseError;
+use crate::fields::Fields;
+// public path since GSVAssembler was introduced
+pub use crate::messages::Assembly;
+use crate::types::talker::TalkerId;
+
+/// Index of first satellite
+const SATELLITES: usize = 3;
+
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct Satellite {
+    pub prn: u16,
+    /// degrees
+    pub elevation: u8,
+    /// degrees
+    pub azimuth: u16,
+    /// dBHz, none when not tracking
+    pub snr: Option<u8>,
+}
+
+impl Satellite {
+    /// None if PRN is empty
+    fn parse(fields: &mut Fields) -> Result<Option<Self>, ParseError> {
+        let prn = fields.parse_option()?;
+        let elevation = fields.parse()?;
+        let azimuth = fields.parse()?;
+        let snr = fields.parse_option()?;
+        Ok(prn.map(|prn| Self { prn, elevation, azimuth, snr }))
+    }
+}
+
+/// Satellites with non-empty PRN, their number and signal ID following them
+fn satellites(fields: &mut Fields) -> Result<([Satellite; 4], u8, Option<u8>), ParseError> {
+    fields.seek(SATELLITES);
+    let num_fields = fields.count();
+    let mut satellites = [Satellite::default(); 4];
+    let mut num_entries = 0;
+    for _ in 0..num_fields / 4 {
+        if let Some(satellite) = Satellite::parse(fields)? {
+            if num_entries < satellites.len() {
+                satellites[num_entries] = satellite;
+                num_entries += 1;
+            }
+        }
+    }
+    let mut signal_id = None;
+    if num_fields % 4 == 1 {
+        signal_id = fields.parse_signal_id()?;
+    }
+    Ok((satellites, num_entries as u8, signal_id))
+}
+
+sentence! {
+    #[derive(Clone, Default, Debug)]
+    pub struct GSV / GSVRef {
+        pub num_sentences: u8 = 0 => Fields::parse,
+        /// starts from 1
+        pub sentence_index: u8 = 1 => Fields::parse,
+        pub num_satellites: u8 = 2 => Fields::parse,
+    }
+    extra satellites {
+        pub satellites: [Satellite; 4],
+        /// number of valid entries in satellites
+        pub num_entries: u8,
+        /// NMEA 4.10 and later
+        pub signal_id: Option<u8>,
+    }
+}
+
+impl GSV {
+    pub fn satellites(&self) -> &[Satellite] {
+        &self.satellites[..self.num_entries as usize]
+    }
+}
+
+impl<'a> GSVRef<'a> {
+    /// Satellites with non-empty PRN, ends after first error
+    pub fn satellites(&self) -> impl Iterator<Item = Result<Satellite, ParseError>> + 'a {
+        let mut fields = Fields::at(self.0, SATELLITES);
+        let mut failed = false;
+        (0..fields.count() / 4).filter_map(move |_| {
+            if failed {
+                return None;
+            }
+            let result = Satellite::parse(&mut fields).transpose();
+            failed = matches!(result, Some(Err(_)));
+            result
+        })
+    }
+
+    /// NMEA 4.10 and later
+    pub fn signal_id(&self) -> Result<Option<u8>, ParseError> {
+        let num_fields = Fields::at(self.0, SATELLITES).count();
+        if num_fields % 4 != 1 {
+            return Ok(None);
+        }
+        Fields::at(self.0, SATELLITES + num_fields - 1).parse_signal_id()
+    }
+}
+
+/// Number of talker and signal ID combinations assembled by GSVAssembler at the same time
+pub const MAX_GSV_GROUPS: usize = 8;
+
+#[derive(Copy, Clone)]
+struct Group<const N: usize> {
+    talker: TalkerId,
+    signal_id: Option<u8>,
+    num_sentences: u8,
+    next_index: u8,
+    num_satellites: u8,
+    satellites: [Satellite; N],
+    length: usize,
+}
+
+impl<const N: usize> Group<N> {
+    fn new(talker: TalkerId, signal_id: Option<u8>) -> Self {
+        Self {
+            talker,
+            signal_id,
+            num_sentences: 0,
+            next_index: 1,
+            num_satellites: 0,
+            satellites: [Satellite::default(); N],
+            length: 0,
+        }
+    }
+
+    fn in_progress(&self) -> bool {
+        self.next_index > 1 && self.next_index <= self.num_sentences
+    }
+
+    fn is_continuation(&self, gsv: &GSV) -> bool {
+        self.in_progress()
+            && self.num_sentences == gsv.num_sentences
+            && self.next_index == gsv.sentence_index
+    }
+
+    /// Returns true once the group is complete
+    fn append(&mut self, gsv: &GSV) -> bool {
+        self.num_satellites = gsv.num_satellites;
+        for satellite in gsv.satellites().iter() {
+            if self.length < N {
+                self.satellites[self.length] = *satellite;
+                self.length += 1;
+            }
+        }
+        self.next_index += 1;
+        self.next_index > self.num_sentences
+    }
+}
+
+/// Collects GSV sentence groups per talker and signal ID into tables of at most N satellites,
+/// satellites exceeding the capacity are dropped. Accessors refer to the group of latest push.
+pub struct GSVAssembler<const N: usize> {
+    groups: [Option<Group<N>>; MAX_GSV_GROUPS],
+    latest: usize,
+}
+
+impl<const N: usize> Default for GSVAssembler<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> GSVAssembler<N> {
+    pub fn new() -> Self {
+        Self { groups: [None; MAX_GSV_GROUPS], latest: 0 }
+    }
+
+    pub fn reset(&mut self) {
+        self.groups = [None; MAX_GSV_GROUPS];
+    }
+
+    fn latest(&self) -> Option<&Group<N>> {
+        self.groups[self.latest].as_ref()
+    }
+
+    pub fn talker(&self) -> TalkerId {
+        self.latest().map(|group| group.talker).unwrap_or(TalkerId::GP)
+    }
+
+    pub fn signal_id(&self) -> Option<u8> {
+        self.latest().and_then(|group| group.signal_id)
+    }
+
+    /// Total satellites in view as reported by the receiver
+    pub fn num_satellites(&self) -> u8 {
+        self.latest().map(|group| group.num_satellites).unwrap_or(0)
+    }
+
+    pub fn satellites(&self) -> &[Satellite] {
+        self.latest().map(|group| &group.satellites[..group.length]).unwrap_or(&[])
+    }
+
+    /// Groups of other talkers and signal IDs are left intact, unless MAX_GSV_GROUPS groups
+    /// are already in progress, in which case the first one is abandoned
+    pub fn push(&mut self, talker: TalkerId, gsv: &GSV) -> Assembly {
+        let key = |group: &Group<N>| group.talker == talker && group.signal_id == gsv.signal_id;
+        let index = match self.groups.iter().position(|group| group.as_ref().is_some_and(key)) {
+            Some(index) => index,
+            None => {
+                let idle = |group: &Option<Group<N>>| !group.is_some_and(|g| g.in_progress());
+                self.groups.iter().position(idle).unwrap_or(0)
+            }
+        };
+        self.latest = index;
+        let slot = &mut self.groups[index];
+        let continued = slot.as_mut().filter(|group| key(group) && group.is_continuation(gsv));
+        if let Some(group) = continued {
+            return if group.append(gsv) { Assembly::Complete } else { Assembly::Pending };
+        }
+        let interrupted = slot.is_some_and(|group| group.in_progress());
+        let group = slot.insert(Group::new(talker, gsv.signal_id));
+        if gsv.sentence_index != 1 || gsv.num_sentences == 0 {
+            return if interrupted { Assembly::Interrupted } else { Assembly::Pending };
+        }
+        group.num_sentences = gsv.num_sentences;
+        match (interrupted, group.append(gsv)) {
+            (true, true) => Assembly::InterruptedComplete,
+            (true, false) => Assembly::Interrupted,
+            (false, true) => Assembly::Complete,
+            (false, false) => Assembly::Pending,
+        }
+    }
+}
+
+mod test {
+    #[test]
+    fn test_gsv() {
+        use core::convert::TryFrom;
+
+        use super::{GSVRef, GSV};
+        use crate::error::ParseError;
+
+        let bytes = b"3,1,10,23,38,230,44,29,71,156,47,07,29,116,41,08,09,081,36";
+        let gsv = GSV::try_from(&bytes[..]).unwrap();
+        assert_eq!(3, gsv.num_sentences);
+        assert_eq!(1, gsv.sentence_index);
+        assert_eq!(10, gsv.num_satellites);
+        assert_eq!(4, gsv.satellites().len());
+        let satellite = gsv.satellites()[0];
+        assert_eq!(
+            (23, 38, 230, Some(44)),
+            (satellite.prn, satellite.elevation, satellite.azimuth, satellite.snr)
+        );
+        assert_eq!(None, gsv.signal_id);
+
+        let bytes = b"3,2,10,10,07,189,,05,05,220,,09,34,274,42,18,25,309,44,1";
+        let gsv = GSV::try_from(&bytes[..]).unwrap();
+        assert_eq!(None, gsv.satellites()[0].snr);
+        assert_eq!(Some(1), gsv.signal_id);
+
+        let gsv = GSVRef::from(&bytes[..]);
+        assert_eq!(Ok(2), gsv.sentence_index());
+        assert_eq!(Ok(vec![10, 5, 9, 18]), gsv.satellites().map(|s| s.map(|s| s.prn)).collect());
+        assert_eq!(Ok(Some(1)), gsv.signal_id());
+
+        let bytes = b"3,3,10,26,82,187,47,28,43,056,46,,,,,,,,";
+        let gsv = GSV::try_from(&bytes[..]).unwrap();
+        assert_eq!(2, gsv.satellites().len());
+        assert_eq!(None, gsv.signal_id);
+
+        let bytes = b"1,1,00";
+        let gsv = GSV::try_from(&bytes[..]).unwrap();
+        assert_eq!(0, gsv.satellites().len());
+
+        let bytes = b",,,,";
+        assert!(GSV::try_from(&bytes[..]).is_ok());
+
+        let bytes = b"3,1,10,2x,38,230,44";
+        assert_eq!(Err(ParseError::InvalidField(3)), GSV::try_from(&bytes[..]).map(|_| ()));
+        assert_eq!(1, GSVRef::from(&bytes[..]).satellites().count());
+        assert_eq!(Ok(None), GSVRef::from(&bytes[..]).signal_id());
+    }
+
+    #[test]
+    fn test_gsv_assembler() {
+        use core::convert::TryFrom;
+
+        use super::{Assembly, GSVAssembler, GSV, MAX_GSV_GROUPS};
+        use crate::types::talker::TalkerId;
+
+        let first =
+            GSV::try_from(&b"3,1,10,23,38,230,44,29,71,156,47,07,29,116,41,08,09,081,36"[..])
+                .unwrap();
+        let second =
+            GSV::try_from(&b"3,2,10,10,07,189,,05,05,220,,09,34,274,42,18,25,309,44"[..]).unwrap();
+        let third = GSV::try_from(&b"3,3,10,26,82,187,47,28,43,056,46"[..]).unwrap();
+
+        let mut assembler = GSVAssembler::<16>::new();
+        assert_eq!(Assembly::Pending, assembler.push(TalkerId::GP, &first));
+        assert_eq!(Assembly::Pending, assembler.push(TalkerId::GP, &second));
+        assert_eq!(Assembly::Complete, assembler.push(TalkerId::GP, &third));
+        assert_eq!(10, assembler.satellites().len());
+        assert_eq!(28, assembler.satellites()[9].prn);
+        assert_eq!(TalkerId::GP, assembler.talker());
+
+        assert_eq!(Assembly::Pending, assembler.push(TalkerId::GP, &first));
+        assert_eq!(Assembly::Pending, assembler.push(TalkerId::GL, &first));
+        assert_eq!(Assembly::Pending, assembler.push(TalkerId::GP, &second));
+        assert_eq!(Assembly::Pending, assembler.push(TalkerId::GL, &second));
+        assert_eq!(Assembly::Complete, assembler.push(TalkerId::GP, &third));
+        assert_eq!(TalkerId::GP, assembler.talker());
+        assert_eq!(Assembly::Complete, assembler.push(TalkerId::GL, &third));
+        assert_eq!((TalkerId::GL, 10), (assembler.talker(), assembler.satellites().len()));
+
+        assert_eq!(Assembly::Pending, assembler.push(TalkerId::GP, &first));
+        assert_eq!(Assembly::Pending, assembler.push(TalkerId::GL, &second));
+        assert_eq!(0, assembler.satellites().len());
+        assert_eq!(Assembly::Interrupted, assembler.push(TalkerId::GP, &third));
+        assert_eq!(0, assembler.satellites().len());
+
+        assert_eq!(Assembly::Pending, assembler.push(TalkerId::GP, &first));
+        assert_eq!(Assembly::Interrupted, assembler.push(TalkerId::GP, &first));
+        assert_eq!(Assembly::Pending, assembler.push(TalkerId::GP, &second));
+        assert_eq!(Assembly::Complete, assembler.push(TalkerId::GP, &third));
+
+        let signal = GSV::try_from(&b"1,1,01,05,05,220,,1"[..]).unwrap();
+        let single = GSV::try_from(&b"1,1,01,05,05,220,"[..]).unwrap();
+        assert_eq!(Assembly::Pending, assembler.push(TalkerId::GP, &first));
+        assert_eq!(Assembly::Complete, assembler.push(TalkerId::GP, &signal));
+        assert_eq!(Some(1), assembler.signal_id());
+        assert_eq!(Assembly::InterruptedComplete, assembler.push(TalkerId::GP, &single));
+        assert_eq!(1, assembler.satellites().len());
+
+        for i in 0..MAX_GSV_GROUPS {
+            let talker = TalkerId::Other([b'X', b'0' + i as u8]);
+            assert_eq!(Assembly::Pending, assembler.push(talker, &first));
+        }
+        assert_eq!(Assembly::Interrupted, assembler.push(TalkerId::GP, &first));
+        assert_eq!(Assembly::Pending, assembler.push(TalkerId::Other(*b"X1"), &second));
+
+        let mut assembler = GSVAssembler::<6>::new();
+        assembler.push(TalkerId::GP, &first);
+        assembler.push(TalkerId::GP, &second);
+        assert_eq!(Assembly::Complete, assembler.push(TalkerId::GP, &third));
+        assert_eq!(6, assembler.satellites().len());
+        assert_eq!(10, assembler.num_satellites());
+    }
+}
@@ -1,4 +1,135 @@
+/// Defines a sentence from its table of fields, each decoded by a function of `&mut Fields`
+/// starting at the field of given index, along with its counterpart borrowing the payload.
+/// Extra fields have no accessor, they are left at default unless decoded together by the
+/// named function before the table.
+#[cfg(feature = "any-sentence")]
+macro_rules! sentence {
+    (@extra $finish:ident $fields:tt) => {
+        $finish $fields?
+    };
+    (@extra $fields:tt) => {
+        Default::default()
+    };
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident / $reference:ident {
+            $(
+                $(#[$field_meta:meta])*
+                pub $field:ident: $type:ty = $index:expr => $decode:expr,
+            )+
+        }
+        $(
+            extra $($finish:ident)? {
+                $(
+                    $(#[$extra_meta:meta])*
+                    pub $extra:ident: $extra_type:ty,
+                )+
+            }
+        )?
+    ) => {
+        $(#[$meta])*
+        pub struct $name {
+            $(
+                $(#[$field_meta])*
+                pub $field: $type,
+            )+
+            $($(
+                $(#[$extra_meta])*
+                pub $extra: $extra_type,
+            )+)?
+        }
+
+        impl $name {
+            /// Decodes fields already split, e.g. along with checksum by the parser
+            pub(crate) fn from_fields(
+                mut fields: $crate::fields::Fields,
+            ) -> Result<Self, $crate::error::ParseError> {
+                let ($($($extra,)+)?) = sentence!(@extra $($($finish)?)? (&mut fields));
+                Ok(Self {
+                    $(
+                        $field: {
+                            fields.seek($index);
+                            ($decode)(&mut fields)?
+                        },
+                    )+
+                    $($($extra,)+)?
+                })
+            }
+        }
+
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = $crate::error::ParseError;
+
+            fn try_from(bytes: &[u8]) -> Result<Self, $crate::error::ParseError> {
+                Self::from_fields($crate::fields::Fields::new(bytes))
+            }
+        }
+
+        #[doc = concat!(stringify!($name), " borrowing its payload, fields are decoded on access")]
+        #[derive(Copy, Clone, Debug)]
+        pub struct $reference<'a>(&'a [u8]);
+
+        impl<'a> From<&'a [u8]> for $reference<'a> {
+            fn from(bytes: &'a [u8]) -> Self {
+                Self(bytes)
+            }
+        }
+
+        impl<'a> $reference<'a> {
+            $(
+                $(#[$field_meta])*
+                pub fn $field(&self) -> Result<$type, $crate::error::ParseError> {
+                    ($decode)(&mut $crate::fields::Fields::at(self.0, $index))
+                }
+            )+
+
+            pub fn decode(&self) -> Result<$name, $crate::error::ParseError> {
+                <$name as core::convert::TryFrom<&[u8]>>::try_from(self.0)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "alm")]
+pub mod alm;
+#[cfg(feature = "dtm")]
+pub mod dtm;
+#[cfg(feature = "gbs")]
+pub mod gbs;
+#[cfg(feature = "gga")]
 pub mod gga;
+#[cfg(feature = "gll")]
+pub mod gll;
+#[cfg(feature = "gns")]
 pub mod gns;
+#[cfg(feature = "grs")]
+pub mod grs;
+#[cfg(feature = "gsa")]
 pub mod gsa;
+#[cfg(feature = "gst")]
+pub mod gst;
+#[cfg(feature = "gsv")]
+pub mod gsv;
+#[cfg(feature = "rmc")]
 pub mod rmc;
+#[cfg(feature = "txt")]
+pub mod txt;
+#[cfg(feature = "vtg")]
+pub mod vtg;
+#[cfg(feature = "zda")]
+pub mod zda;
+
+/// Progress of assembling a multi-sentence group
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Assembly {
+    /// Group still expects more sentences
+    Pending,
+    /// Group complete, assembled data available until next push
+    Complete,
+    /// Group in progress abandoned since the sentence does not continue it,
+    /// a first sentence still starts a new group
+    Interrupted,
+    /// Group in progress abandoned and the sentence completes a new group on its own,
+    /// assembled data available until next push
+    InterruptedComplete,
+}
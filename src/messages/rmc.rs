@@ -1,71 +1,100 @@
+use crate::error::ParseError;
+use crate::fields::Fields;
 use crate::types::date::Date;
+use crate::types::datum::Datum;
 use crate::types::latitude::Latitude;
 use crate::types::longitude::Longitude;
 use crate::types::position_mode::PositionMode;
 use crate::types::time::Time;
-use crate::types::{IntegerDecimal, Status};
+use crate::types::{IntegerDecimal, NavigationalStatus, Status};
 
-#[derive(Clone, Default, Debug)]
-pub struct RMC {
-    pub time: Time,
-    pub status: Status,
-    pub latitude: Latitude,
-    pub longitude: Longitude,
-    /// knots
-    pub speed: IntegerDecimal,
-    pub course: IntegerDecimal,
-    pub date: Date,
-    pub heading: Option<IntegerDecimal>,
-    pub position_mode: PositionMode,
+/// Variation followed by E or W
+fn magnetic_variation(fields: &mut Fields) -> Result<Option<IntegerDecimal>, ParseError> {
+    let magnetic_variation: Option<IntegerDecimal> = fields.parse_option()?;
+    let mvew = fields.next()?;
+    Ok(magnetic_variation.map(|v| if mvew == b"W" { -v } else { v }))
 }
 
-impl From<&[u8]> for RMC {
-    fn from(bytes: &[u8]) -> Self {
-        let mut fields = bytes.split(|&b| b == b',');
-        let time = Time::from(fields.next().unwrap());
-        let status = Status::from(fields.next().unwrap());
-        let mut latitude = Latitude::from(fields.next().unwrap());
-        if fields.next().unwrap() == b"S" {
-            latitude.0 = -latitude.0;
-        }
-        let mut longitude = Longitude::from(fields.next().unwrap());
-        if fields.next().unwrap() == b"W" {
-            longitude.0 = -longitude.0;
-        }
-        let speed: IntegerDecimal = fields.next().unwrap_or(b"0.0").into();
-        let course: IntegerDecimal = fields.next().unwrap().into();
-        let date: Date = fields.next().unwrap().into();
-        let mut heading: Option<IntegerDecimal> = None;
-        let field = fields.next().unwrap();
-        let mvew = fields.next().unwrap();
-        if field.len() > 0 {
-            let mut value: IntegerDecimal = field.into();
-            if mvew == b"W" {
-                value += 180;
-            }
-            heading = Some(value);
-        }
-        let position_mode = PositionMode::from(fields.next().unwrap());
-        Self { time, status, latitude, longitude, speed, course, date, heading, position_mode }
+sentence! {
+    #[derive(Clone, Default, Debug)]
+    pub struct RMC / RMCRef {
+        pub time: Option<Time> = 0 => Fields::parse_option,
+        pub status: Option<Status> = 1 => Fields::parse_option,
+        pub latitude: Option<Latitude> = 2 => Fields::parse_latitude,
+        pub longitude: Option<Longitude> = 4 => Fields::parse_longitude,
+        /// knots
+        pub speed: Option<IntegerDecimal> = 6 => Fields::parse_option,
+        /// degrees
+        pub course: Option<IntegerDecimal> = 7 => Fields::parse_option,
+        pub date: Option<Date> = 8 => Fields::parse_option,
+        /// degrees, easterly positive and westerly negative
+        pub magnetic_variation: Option<IntegerDecimal> = 9 => magnetic_variation,
+        /// NMEA 2.3 and later
+        pub position_mode: Option<PositionMode> = 11 => Fields::parse_trailing,
+        /// NMEA 4.10 and later
+        pub navigational_status: Option<NavigationalStatus> = 12 => Fields::parse_trailing,
+    }
+    extra {
+        /// WGS84 unless announced otherwise by DTM of the same talker
+        pub datum: Datum,
     }
 }
 
 mod test {
     #[test]
     fn test_rmc() {
-        use super::RMC;
+        use core::convert::TryFrom;
+
+        use super::{RMCRef, RMC};
+        use crate::error::ParseError;
+        use crate::types::NavigationalStatus;
+
+        let bytes = b"083559.00,A,4717.11437,N,00833.91522,E,0.004,77.52,091202,,,A,V";
+        let rmc = RMC::try_from(&bytes[..]).unwrap();
+        assert_eq!("Some(08:35:59.00)", format!("{:?}", rmc.time));
+        assert_eq!(r#"Some(N47°17'11"437)"#, format!("{:?}", rmc.latitude));
+        assert_eq!(r#"Some(E008°33'91"522)"#, format!("{:?}", rmc.longitude));
+        assert_eq!("Some(0.4#3)", format!("{:?}", rmc.speed));
+        assert_eq!("Some(77.52#2)", format!("{:?}", rmc.course));
+        assert_eq!("Some(021209)", format!("{:?}", rmc.date));
+        assert!(rmc.magnetic_variation.is_none());
+        assert_eq!("Some(true)", format!("{:?}", rmc.status.map(|status| status.0)));
+        assert_eq!("Some(Autonomous)", format!("{:?}", rmc.position_mode));
+        assert_eq!(Some(NavigationalStatus::NotValid), rmc.navigational_status);
 
-        let bytes = b"083559.00,A,4717.11437,N,00833.91522,E,0.004,77.52,091202,,,A,V*57";
-        let rmc = RMC::from(&bytes[..]);
-        assert_eq!("08:35:59.00", format!("{:?}", rmc.time));
-        assert_eq!(r#"N47°17'11"437"#, format!("{:?}", rmc.latitude));
-        assert_eq!(r#"E008°33'91"522"#, format!("{:?}", rmc.longitude));
-        assert_eq!("0.4#3", format!("{:?}", rmc.speed));
-        assert_eq!("77.52#2", format!("{:?}", rmc.course));
-        assert_eq!("021209", format!("{:?}", rmc.date));
-        assert_eq!("Autonomous", format!("{:?}", rmc.position_mode));
+        let bytes = b"225446.33,A,4916.45,N,12311.12,W,000.5,054.7,191194,020.3,W,D,S";
+        let rmc = RMC::try_from(&bytes[..]).unwrap();
+        assert_eq!(Some(-203), rmc.magnetic_variation.map(|v| v.real()));
+        assert_eq!("Some(Differential)", format!("{:?}", rmc.position_mode));
+        assert_eq!("Some(Safe)", format!("{:?}", rmc.navigational_status));
+
+        let rmc = RMCRef::from(&bytes[..]);
+        assert_eq!(Ok(Some(-203)), rmc.magnetic_variation().map(|v| v.map(|v| v.real())));
+        assert_eq!("Ok(Some(Differential))", format!("{:?}", rmc.position_mode()));
+        assert_eq!("Ok(Some(941119))", format!("{:?}", rmc.date()));
+
+        let bytes = b"225446.33,A,4916.45,N,12311.12,W,000.5,054.7,191194,003.1,E";
+        let rmc = RMC::try_from(&bytes[..]).unwrap();
+        assert_eq!("Some(3.1#1)", format!("{:?}", rmc.magnetic_variation));
+        assert!(rmc.navigational_status.is_none());
+        assert!(rmc.position_mode.is_none());
 
         let bytes = b"083559.00,,,,,,,,,,,,";
-        let _ = RMC::from(&bytes[..]);
+        let rmc = RMC::try_from(&bytes[..]).unwrap();
+        assert!(rmc.latitude.is_none() && rmc.longitude.is_none());
+        assert!(rmc.speed.is_none() && rmc.course.is_none() && rmc.date.is_none());
+        assert!(rmc.status.is_none() && rmc.position_mode.is_none());
+        let rmc = RMCRef::from(&bytes[..]);
+        assert_eq!(Ok(None), rmc.status().map(|status| status.map(|status| status.0)));
+        assert_eq!(Ok(None), rmc.position_mode());
+
+        let bytes = b"083559.00,X,4717.11437,N,00833.91522,E,0.004,77.52,091202,,,A";
+        assert_eq!(Err(ParseError::InvalidField(1)), RMC::try_from(&bytes[..]).map(|_| ()));
+        let bytes = b"083559.00,A,4717.11437,N,00833.91522,E,0.004,77.52,091202,,,X,V";
+        assert_eq!(Err(ParseError::InvalidField(11)), RMCRef::from(&bytes[..]).position_mode());
+        let bytes = b"083559.00,A,4717.11437,N,00833.91522,E,0.004,77.52,091202,,,A,X";
+        assert_eq!(Err(ParseError::InvalidField(12)), RMC::try_from(&bytes[..]).map(|_| ()));
+        let bytes = b"083559.00,A,4717.11437,N,00833.91522,E,0.004,77.52,0912X2,,,A";
+        assert_eq!(Err(ParseError::InvalidField(8)), RMC::try_from(&bytes[..]).map(|_| ()));
     }
 }
@@ -0,0 +1,194 @@
+use crate::error::ParseError;
+use crate::fields::{Fields, FromField, Hex};
+use crate::messages::Assembly;
+use crate::types::string::FixedString;
+use crate::types::talker::TalkerId;
+
+/// Maximum length of text in a single TXT sentence
+pub const MAX_TEXT_LENGTH: usize = 61;
+
+/// Index of text, which may contain commas
+const TEXT: usize = 3;
+
+/// Decodes `^hh` reserved character escapes, returns false if any byte is dropped
+fn decode<const N: usize>(bytes: &[u8], string: &mut FixedString<N>) -> bool {
+    let mut all = true;
+    let mut i = 0;
+    while i < bytes.len() {
+        let mut byte = bytes[i];
+        let escape = bytes.get(i + 1..i + 3).and_then(Hex::<u8>::from_field);
+        if let (b'^', Some(Hex(hh))) = (byte, escape) {
+            byte = hh;
+            i += 2;
+        }
+        all = string.push(byte) && all;
+        i += 1;
+    }
+    all
+}
+
+fn text(fields: &mut Fields) -> Result<FixedString<MAX_TEXT_LENGTH>, ParseError> {
+    let mut text = FixedString::default();
+    decode(fields.rest()?, &mut text);
+    Ok(text)
+}
+
+sentence! {
+    #[derive(Clone, Default, Debug)]
+    pub struct TXT / TXTRef {
+        pub num_sentences: u8 = 0 => Fields::parse,
+        /// starts from 1
+        pub sentence_index: u8 = 1 => Fields::parse,
+        /// e.g. 00 for error, 01 for warning, 02 for notice and 07 for user on u-blox
+        pub identifier: u8 = 2 => Fields::parse,
+        pub text: FixedString<MAX_TEXT_LENGTH> = TEXT => text,
+    }
+}
+
+impl<'a> TXTRef<'a> {
+    /// Text as received, reserved characters still escaped
+    pub fn raw_text(&self) -> Result<&'a [u8], ParseError> {
+        Fields::at(self.0, TEXT).rest()
+    }
+}
+
+/// Concatenates text of a TXT sentence group of one talker into a string of at most N bytes,
+/// text exceeding the capacity is dropped
+pub struct TXTAssembler<const N: usize> {
+    talker: TalkerId,
+    identifier: u8,
+    num_sentences: u8,
+    next_index: u8,
+    text: FixedString<N>,
+}
+
+impl<const N: usize> Default for TXTAssembler<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> TXTAssembler<N> {
+    pub fn new() -> Self {
+        Self {
+            talker: TalkerId::GP,
+            identifier: 0,
+            num_sentences: 0,
+            next_index: 1,
+            text: FixedString::default(),
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.next_index = 1;
+        self.text.clear();
+    }
+
+    pub fn talker(&self) -> TalkerId {
+        self.talker
+    }
+
+    pub fn identifier(&self) -> u8 {
+        self.identifier
+    }
+
+    pub fn text(&self) -> &str {
+        self.text.as_str()
+    }
+
+    fn is_continuation(&self, talker: TalkerId, txt: &TXT) -> bool {
+        self.talker == talker
+            && self.identifier == txt.identifier
+            && self.num_sentences == txt.num_sentences
+            && self.next_index == txt.sentence_index
+    }
+
+    pub fn push(&mut self, talker: TalkerId, txt: &TXT) -> Assembly {
+        let in_progress = self.next_index > 1 && self.next_index <= self.num_sentences;
+        let mut interrupted = false;
+        if !in_progress || !self.is_continuation(talker, txt) {
+            interrupted = in_progress;
+            self.reset();
+            if txt.sentence_index != 1 || txt.num_sentences == 0 {
+                return if interrupted { Assembly::Interrupted } else { Assembly::Pending };
+            }
+            self.talker = talker;
+            self.identifier = txt.identifier;
+            self.num_sentences = txt.num_sentences;
+        }
+        self.text.push_bytes(txt.text.as_bytes());
+        self.next_index += 1;
+        match (interrupted, self.next_index > self.num_sentences) {
+            (true, true) => Assembly::InterruptedComplete,
+            (true, false) => Assembly::Interrupted,
+            (false, true) => Assembly::Complete,
+            (false, false) => Assembly::Pending,
+        }
+    }
+}
+
+mod test {
+    #[test]
+    fn test_txt() {
+        use core::convert::TryFrom;
+
+        use super::{TXTRef, TXT};
+        use crate::error::ParseError;
+
+        let bytes = b"01,01,02,ANTSTATUS=OK";
+        let txt = TXT::try_from(&bytes[..]).unwrap();
+        assert_eq!((1, 1, 2), (txt.num_sentences, txt.sentence_index, txt.identifier));
+        assert_eq!("ANTSTATUS=OK", txt.text.as_str());
+
+        let bytes = b"01,01,02,u-blox ag - www.u-blox.com";
+        let txt = TXT::try_from(&bytes[..]).unwrap();
+        assert_eq!("u-blox ag - www.u-blox.com", txt.text.as_str());
+
+        let bytes = b"01,01,07,a^2Cb^2Ac^5Ed^zz^2";
+        let txt = TXT::try_from(&bytes[..]).unwrap();
+        assert_eq!("a,b*c^d^zz^2", txt.text.as_str());
+
+        let txt = TXTRef::from(&bytes[..]);
+        assert_eq!(Ok(7), txt.identifier());
+        assert_eq!(Ok(&b"a^2Cb^2Ac^5Ed^zz^2"[..]), txt.raw_text());
+        assert_eq!(Ok("a,b*c^d^zz^2"), txt.text().as_ref().map(|text| text.as_str()));
+
+        let bytes = b"01,01";
+        assert_eq!(Err(ParseError::MissingField(2)), TXT::try_from(&bytes[..]).map(|_| ()));
+    }
+
+    #[test]
+    fn test_txt_assembler() {
+        use core::convert::TryFrom;
+
+        use super::{TXTAssembler, TXT};
+        use crate::messages::Assembly;
+        use crate::types::talker::TalkerId;
+
+        let first = TXT::try_from(&b"02,01,02,HW UBX-M8030 ^2C"[..]).unwrap();
+        let second = TXT::try_from(&b"02,02,02,ROM CORE 3.01"[..]).unwrap();
+
+        let mut assembler = TXTAssembler::<128>::new();
+        assert_eq!(Assembly::Pending, assembler.push(TalkerId::GP, &first));
+        assert_eq!(Assembly::Complete, assembler.push(TalkerId::GP, &second));
+        assert_eq!("HW UBX-M8030 ,ROM CORE 3.01", assembler.text());
+        assert_eq!(2, assembler.identifier());
+
+        assert_eq!(Assembly::Pending, assembler.push(TalkerId::GP, &first));
+        assert_eq!(Assembly::Interrupted, assembler.push(TalkerId::GN, &second));
+        assert_eq!("", assembler.text());
+
+        let single = TXT::try_from(&b"01,01,02,ANTSTATUS=OK"[..]).unwrap();
+        assert_eq!(Assembly::Complete, assembler.push(TalkerId::GP, &single));
+        assert_eq!("ANTSTATUS=OK", assembler.text());
+
+        assert_eq!(Assembly::Pending, assembler.push(TalkerId::GP, &first));
+        assert_eq!(Assembly::InterruptedComplete, assembler.push(TalkerId::GP, &single));
+        assert_eq!("ANTSTATUS=OK", assembler.text());
+
+        let mut assembler = TXTAssembler::<16>::new();
+        assembler.push(TalkerId::GP, &first);
+        assert_eq!(Assembly::Complete, assembler.push(TalkerId::GP, &second));
+        assert_eq!("HW UBX-M8030 ,RO", assembler.text());
+    }
+}
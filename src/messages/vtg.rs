@@ -0,0 +1,47 @@
+use crate::fields::Fields;
+use crate::types::position_mode::PositionMode;
+use crate::types::IntegerDecimal;
+
+sentence! {
+    #[derive(Clone, Default, Debug)]
+    pub struct VTG / VTGRef {
+        /// degrees
+        pub course: IntegerDecimal = 0 => Fields::parse,
+        /// degrees
+        pub magnetic_course: Option<IntegerDecimal> = 2 => Fields::parse_option,
+        /// knots
+        pub speed: IntegerDecimal = 4 => Fields::parse,
+        /// km/h
+        pub speed_kph: IntegerDecimal = 6 => Fields::parse,
+        /// NMEA 2.3 and later
+        pub position_mode: PositionMode = 8 => Fields::parse_trailing_from,
+    }
+}
+
+mod test {
+    #[test]
+    fn test_vtg() {
+        use core::convert::TryFrom;
+
+        use super::VTG;
+
+        let bytes = b"77.52,T,,M,0.004,N,0.008,K,A";
+        let vtg = VTG::try_from(&bytes[..]).unwrap();
+        assert_eq!("77.52#2", format!("{:?}", vtg.course));
+        assert!(vtg.magnetic_course.is_none());
+        assert_eq!("0.4#3", format!("{:?}", vtg.speed));
+        assert_eq!("0.8#3", format!("{:?}", vtg.speed_kph));
+        assert_eq!("Autonomous", format!("{:?}", vtg.position_mode));
+
+        let bytes = b"054.7,T,034.4,M,005.5,N,010.2,K";
+        let vtg = VTG::try_from(&bytes[..]).unwrap();
+        assert_eq!("54.7#1", format!("{:?}", vtg.course));
+        assert_eq!("Some(34.4#1)", format!("{:?}", vtg.magnetic_course));
+        assert_eq!("5.5#1", format!("{:?}", vtg.speed));
+        assert_eq!("10.2#1", format!("{:?}", vtg.speed_kph));
+        assert_eq!("NoFix", format!("{:?}", vtg.position_mode));
+
+        let bytes = b",,,,,,,,";
+        assert!(VTG::try_from(&bytes[..]).is_ok());
+    }
+}
@@ -0,0 +1,72 @@
+use crate::error::ParseError;
+use crate::fields::Fields;
+use crate::types::time::Time;
+
+/// Index of local zone hours, followed by minutes
+const LOCAL_ZONE: usize = 4;
+
+/// Hours and minutes of local zone, minutes take the sign of hours
+fn local_zone(fields: &mut Fields) -> Result<(i8, i8), ParseError> {
+    fields.seek(LOCAL_ZONE);
+    let hours: i8 = fields.parse_trailing()?.unwrap_or(0);
+    let mut minutes: i8 = fields.parse_trailing()?.unwrap_or(0);
+    if hours < 0 && minutes > 0 {
+        minutes = -minutes;
+    }
+    Ok((hours, minutes))
+}
+
+sentence! {
+    #[derive(Clone, Default, Debug)]
+    pub struct ZDA / ZDARef {
+        /// UTC
+        pub time: Time = 0 => Fields::parse,
+        pub day: u8 = 1 => Fields::parse,
+        pub month: u8 = 2 => Fields::parse,
+        pub year: u16 = 3 => Fields::parse,
+    }
+    extra local_zone {
+        /// local zone = UTC + offset, -13 to 13
+        pub local_zone_hours: i8,
+        /// same sign as local zone hours
+        pub local_zone_minutes: i8,
+    }
+}
+
+impl<'a> ZDARef<'a> {
+    /// local zone = UTC + offset, as hours and minutes of the same sign
+    pub fn local_zone(&self) -> Result<(i8, i8), ParseError> {
+        local_zone(&mut Fields::at(self.0, LOCAL_ZONE))
+    }
+}
+
+mod test {
+    #[test]
+    fn test_zda() {
+        use core::convert::TryFrom;
+
+        use super::{ZDARef, ZDA};
+        use crate::error::ParseError;
+
+        let bytes = b"082710.00,16,09,2002,00,00";
+        let zda = ZDA::try_from(&bytes[..]).unwrap();
+        assert_eq!("08:27:10.00", format!("{:?}", zda.time));
+        assert_eq!((16, 9, 2002), (zda.day, zda.month, zda.year));
+        assert_eq!((0, 0), (zda.local_zone_hours, zda.local_zone_minutes));
+
+        let bytes = b"201530.00,04,07,2002,-03,30";
+        let zda = ZDA::try_from(&bytes[..]).unwrap();
+        assert_eq!((-3, -30), (zda.local_zone_hours, zda.local_zone_minutes));
+
+        assert_eq!(Ok((-3, -30)), ZDARef::from(&bytes[..]).local_zone());
+        assert_eq!(Ok(7), ZDARef::from(&bytes[..]).month());
+
+        let bytes = b"201530.00,04,07,2002,";
+        let zda = ZDA::try_from(&bytes[..]).unwrap();
+        assert_eq!(2002, zda.year);
+        assert_eq!((0, 0), (zda.local_zone_hours, zda.local_zone_minutes));
+
+        let bytes = b",,";
+        assert_eq!(Err(ParseError::MissingField(3)), ZDA::try_from(&bytes[..]).map(|_| ()));
+    }
+}
@@ -0,0 +1,174 @@
+use core::str::from_utf8;
+
+use crate::types::talker::TalkerId;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum StartDelimiter {
+    /// `$`, conventional parametric sentence
+    Parametric,
+    /// `!`, encapsulation sentence, e.g. AIS
+    Encapsulation,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Address<'a> {
+    /// talker ID and sentence formatter
+    Standard(TalkerId, &'a [u8]),
+    /// manufacturer code and remaining address characters, which may be empty
+    Proprietary(&'a [u8], &'a [u8]),
+}
+
+impl<'a> Address<'a> {
+    /// None if address is malformed
+    pub(crate) fn parse(address: &'a [u8]) -> Option<Self> {
+        if !address.iter().all(u8::is_ascii_alphanumeric) {
+            return None;
+        }
+        if address.first() == Some(&b'P') && address.len() >= 4 {
+            Some(Self::Proprietary(&address[1..4], &address[4..]))
+        } else if address.len() >= 5 {
+            Some(Self::Standard(TalkerId::from([address[0], address[1]]), &address[2..]))
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ChecksumStatus {
+    Valid,
+    Invalid,
+    /// checksum field is optional for some sentences
+    Missing,
+}
+
+/// Sentence validated only by framing, borrowing the original bytes
+#[derive(Copy, Clone, Debug)]
+pub struct RawSentence<'a> {
+    delimiter: StartDelimiter,
+    address: Address<'a>,
+    /// bytes between first comma and checksum delimiter
+    payload: Option<&'a [u8]>,
+    checksum_status: ChecksumStatus,
+}
+
+impl<'a> RawSentence<'a> {
+    /// Accepts a line starting with `$` or `!`, excluding trailing CR and LF
+    pub fn try_from(line: &'a [u8]) -> Option<Self> {
+        let delimiter = match line.first() {
+            Some(b'$') => StartDelimiter::Parametric,
+            Some(b'!') => StartDelimiter::Encapsulation,
+            _ => return None,
+        };
+        let line = &line[1..];
+        let (body, checksum_status) = match line.iter().rposition(|&b| b == b'*') {
+            Some(index) => {
+                let (body, checksum) = (&line[..index], &line[index + 1..]);
+                let expected =
+                    from_utf8(checksum).ok().and_then(|c| u8::from_str_radix(c, 16).ok());
+                let actual = body.iter().fold(0, |csum, &b| csum ^ b);
+                let hex = checksum.len() == 2 && checksum.iter().all(u8::is_ascii_hexdigit);
+                let valid = hex && expected == Some(actual);
+                (body, if valid { ChecksumStatus::Valid } else { ChecksumStatus::Invalid })
+            }
+            None => (line, ChecksumStatus::Missing),
+        };
+
+        let mut splitted = body.splitn(2, |&b| b == b',');
+        let address = Address::parse(splitted.next().unwrap_or(b""))?;
+        let payload = splitted.next();
+        Some(Self { delimiter, address, payload, checksum_status })
+    }
+
+    pub fn delimiter(&self) -> StartDelimiter {
+        self.delimiter
+    }
+
+    pub fn address(&self) -> Address<'a> {
+        self.address
+    }
+
+    pub fn talker(&self) -> Option<TalkerId> {
+        match self.address {
+            Address::Standard(talker, _) => Some(talker),
+            Address::Proprietary(..) => None,
+        }
+    }
+
+    pub fn sentence_formatter(&self) -> Option<&'a [u8]> {
+        match self.address {
+            Address::Standard(_, formatter) => Some(formatter),
+            Address::Proprietary(..) => None,
+        }
+    }
+
+    pub fn manufacturer(&self) -> Option<&'a [u8]> {
+        match self.address {
+            Address::Standard(..) => None,
+            Address::Proprietary(manufacturer, _) => Some(manufacturer),
+        }
+    }
+
+    pub fn is_proprietary(&self) -> bool {
+        self.manufacturer().is_some()
+    }
+
+    pub fn checksum_status(&self) -> ChecksumStatus {
+        self.checksum_status
+    }
+
+    /// Bytes between first comma and checksum delimiter
+    pub fn payload(&self) -> &'a [u8] {
+        self.payload.unwrap_or(b"")
+    }
+
+    /// Comma separated data fields following the address
+    pub fn fields(&self) -> impl Iterator<Item = &'a [u8]> + 'a {
+        self.payload.into_iter().flat_map(|payload| payload.split(|&b| b == b','))
+    }
+}
+
+mod test {
+    #[test]
+    fn test_raw_sentence() {
+        use super::{Address, ChecksumStatus, RawSentence, StartDelimiter};
+        use crate::types::talker::TalkerId;
+
+        let line = b"$GPGLL,4717.11364,N,00833.91565,E,092321.00,A,A*60";
+        let raw = RawSentence::try_from(&line[..]).unwrap();
+        assert_eq!(StartDelimiter::Parametric, raw.delimiter());
+        assert_eq!(Address::Standard(TalkerId::GP, b"GLL"), raw.address());
+        assert_eq!(ChecksumStatus::Valid, raw.checksum_status());
+        assert_eq!(7, raw.fields().count());
+        assert_eq!(Some(&b"00833.91565"[..]), raw.fields().nth(2));
+
+        let line = b"!AIVDM,1,1,,A,13aEOK?P00PD2wVMdLDRhgvL289?,0*26";
+        let raw = RawSentence::try_from(&line[..]).unwrap();
+        assert_eq!(StartDelimiter::Encapsulation, raw.delimiter());
+        assert_eq!(Some(TalkerId::AI), raw.talker());
+        assert_eq!(Some(&b"VDM"[..]), raw.sentence_formatter());
+        assert_eq!(ChecksumStatus::Valid, raw.checksum_status());
+
+        let line = b"$PUBX,00,081350.00,4717.113210,N*00";
+        let raw = RawSentence::try_from(&line[..]).unwrap();
+        assert_eq!(Address::Proprietary(b"UBX", b""), raw.address());
+        assert!(raw.is_proprietary());
+        assert_eq!(ChecksumStatus::Invalid, raw.checksum_status());
+        assert_eq!(Some(&b"00"[..]), raw.fields().next());
+
+        let line = b"$PGRME,15.0,M,45.0,M,25.0,M";
+        let raw = RawSentence::try_from(&line[..]).unwrap();
+        assert_eq!(Some(&b"GRM"[..]), raw.manufacturer());
+        assert_eq!(Address::Proprietary(b"GRM", b"E"), raw.address());
+        assert_eq!(ChecksumStatus::Missing, raw.checksum_status());
+
+        let line = b"$GPXYZ*00";
+        let raw = RawSentence::try_from(&line[..]).unwrap();
+        assert_eq!(0, raw.fields().count());
+
+        assert!(RawSentence::try_from(&b"GPGGA,"[..]).is_none());
+        assert!(RawSentence::try_from(&b"$GP,"[..]).is_none());
+        assert!(RawSentence::try_from(&b"$"[..]).is_none());
+        assert!(RawSentence::try_from(&b"$GP GGA,"[..]).is_none());
+    }
+}
@@ -1,4 +1,7 @@
-use core::str::from_utf8_unchecked as utf8;
+use core::convert::TryFrom;
+
+use crate::error::ParseError;
+use crate::fields::integer;
 
 #[derive(Copy, Clone, Default, PartialEq)]
 pub struct Date {
@@ -7,14 +10,24 @@ pub struct Date {
     pub day: u8,
 }
 
-impl From<&[u8]> for Date {
-    fn from(bytes: &[u8]) -> Self {
-        let ddmmyy: u32 = unsafe { utf8(bytes) }.parse().unwrap_or(0);
-        Date {
+impl TryFrom<&[u8]> for Date {
+    type Error = ParseError;
+
+    /// Accepts `ddmmyy`
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        let ddmmyy = match integer(bytes) {
+            Some(ddmmyy) if bytes.len() == 6 => ddmmyy,
+            _ => return Err(ParseError::InvalidField(0)),
+        };
+        let date = Date {
             year: (ddmmyy % 100) as u8,
             month: ((ddmmyy / 100) % 100) as u8,
             day: (ddmmyy / 10000) as u8,
+        };
+        if !(1..=12).contains(&date.month) || !(1..=31).contains(&date.day) {
+            return Err(ParseError::InvalidField(0));
         }
+        Ok(date)
     }
 }
 
@@ -0,0 +1,75 @@
+use crate::types::talker::TalkerId;
+
+/// Number of talkers whose local datum is tracked by the parser
+pub const MAX_DATUM_TALKERS: usize = 8;
+
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub enum Datum {
+    #[default]
+    WGS84,
+    WGS72,
+    SGS85,
+    PE90,
+    UserDefined,
+    /// IHO datum code
+    Other([u8; 3]),
+}
+
+impl From<&[u8]> for Datum {
+    fn from(bytes: &[u8]) -> Self {
+        match bytes {
+            b"W84" | b"" => Self::WGS84,
+            b"W72" => Self::WGS72,
+            b"S85" => Self::SGS85,
+            b"P90" => Self::PE90,
+            b"999" => Self::UserDefined,
+            _ => {
+                let mut code = [b' '; 3];
+                let length = core::cmp::min(bytes.len(), 3);
+                code[..length].copy_from_slice(&bytes[..length]);
+                Self::Other(code)
+            }
+        }
+    }
+}
+
+/// Local datum announced by DTM per talker, WGS84 unless announced otherwise
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Datums([Option<(TalkerId, Datum)>; MAX_DATUM_TALKERS]);
+
+impl Datums {
+    pub fn get(&self, talker: TalkerId) -> Datum {
+        let entry = self.0.iter().flatten().find(|(t, _)| *t == talker);
+        entry.map(|&(_, datum)| datum).unwrap_or_default()
+    }
+
+    /// Replaces datum of the first talker if there are already MAX_DATUM_TALKERS talkers
+    pub fn set(&mut self, talker: TalkerId, datum: Datum) {
+        let mut index = self.0.iter().position(|e| e.map(|(t, _)| t) == Some(talker));
+        index = index.or_else(|| self.0.iter().position(|e| e.is_none()));
+        let entry = if datum != Datum::WGS84 { Some((talker, datum)) } else { None };
+        self.0[index.unwrap_or(0)] = entry;
+    }
+}
+
+mod test {
+    #[test]
+    fn test_datums() {
+        use super::{Datum, Datums, MAX_DATUM_TALKERS};
+        use crate::types::talker::TalkerId;
+
+        let mut datums = Datums::default();
+        datums.set(TalkerId::GL, Datum::PE90);
+        assert_eq!(Datum::PE90, datums.get(TalkerId::GL));
+        assert_eq!(Datum::WGS84, datums.get(TalkerId::GP));
+
+        datums.set(TalkerId::GL, Datum::WGS84);
+        assert_eq!(Datum::WGS84, datums.get(TalkerId::GL));
+
+        for i in 0..=MAX_DATUM_TALKERS {
+            datums.set(TalkerId::Other([b'X', b'0' + i as u8]), Datum::UserDefined);
+        }
+        assert_eq!(Datum::WGS84, datums.get(TalkerId::Other(*b"X0")));
+        assert_eq!(Datum::UserDefined, datums.get(TalkerId::Other(*b"X1")));
+    }
+}
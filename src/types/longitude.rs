@@ -1,4 +1,7 @@
-use core::str::from_utf8_unchecked as utf8;
+use core::convert::TryFrom;
+
+use crate::error::ParseError;
+use crate::fields::fixed_point;
 
 #[derive(Copy, Clone, Default, PartialEq)]
 pub struct Longitude(pub i32);
@@ -29,21 +32,17 @@ impl Longitude {
     }
 }
 
-impl From<&[u8]> for Longitude {
-    fn from(bytes: &[u8]) -> Self {
-        if bytes.len() == 0 {
-            return Self::default();
-        }
-        let mut s = bytes.split(|&b| b == b'.');
-        let mut integer = 0i32;
-        if let Some(field) = s.next() {
-            integer = unsafe { utf8(field) }.parse().unwrap_or(0);
-        }
-        let mut decimal = 0i32;
-        if let Some(field) = s.next() {
-            decimal = unsafe { utf8(field) }.parse().unwrap_or(0);
+impl TryFrom<&[u8]> for Longitude {
+    type Error = ParseError;
+
+    /// Accepts unsigned `dddmm.mmmmm`, fraction beyond 5 digits is truncated
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        match fixed_point(bytes, 5) {
+            Some((integer, decimal)) if integer <= 18000 => {
+                Ok(Self(integer as i32 * 100000 + decimal as i32))
+            }
+            _ => Err(ParseError::InvalidField(0)),
         }
-        Self(integer * 100000 + decimal)
     }
 }
 
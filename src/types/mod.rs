@@ -1,66 +1,105 @@
+use core::convert::TryFrom;
 use core::fmt::Debug;
-use core::str::from_utf8_unchecked as utf8;
+
+use crate::error::ParseError;
 
 pub mod date;
+pub mod datum;
 pub mod latitude;
 pub mod longitude;
 pub mod position_mode;
+pub mod string;
+pub mod system;
+pub mod talker;
 pub mod time;
 
 pub type Quality = position_mode::PositionMode;
 
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
 pub enum OperationMode {
     Auto,
+    #[default]
     Manual,
 }
 
-impl Default for OperationMode {
-    fn default() -> Self {
-        Self::Manual
+impl OperationMode {
+    pub fn try_from(bytes: &[u8]) -> Option<Self> {
+        match bytes {
+            b"A" => Some(Self::Auto),
+            b"M" => Some(Self::Manual),
+            _ => None,
+        }
     }
 }
 
 impl From<&[u8]> for OperationMode {
     fn from(bytes: &[u8]) -> Self {
-        match bytes.first().map(|&b| b).unwrap_or(b'M') {
-            b'A' => Self::Auto,
-            b'M' => Self::Manual,
-            _ => Self::Manual,
-        }
+        Self::try_from(bytes).unwrap_or_default()
     }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
 pub enum NavigationMode {
+    #[default]
     NoFix,
     _3DFix,
     _2DFix,
 }
 
-impl Default for NavigationMode {
-    fn default() -> Self {
-        Self::NoFix
+impl NavigationMode {
+    pub fn try_from(bytes: &[u8]) -> Option<Self> {
+        match bytes {
+            b"1" => Some(Self::NoFix),
+            b"2" => Some(Self::_2DFix),
+            b"3" => Some(Self::_3DFix),
+            _ => None,
+        }
     }
 }
 
 impl From<&[u8]> for NavigationMode {
     fn from(bytes: &[u8]) -> Self {
-        match bytes.first().map(|&b| b).unwrap_or(b'1') {
-            b'1' => Self::NoFix,
-            b'2' => Self::_2DFix,
-            b'3' => Self::_3DFix,
-            _ => Self::NoFix,
-        }
+        Self::try_from(bytes).unwrap_or_default()
     }
 }
 
 #[derive(Copy, Clone, Default, Debug, PartialEq)]
 pub struct Status(pub bool);
 
+impl Status {
+    pub fn try_from(bytes: &[u8]) -> Option<Self> {
+        match bytes {
+            b"A" => Some(Self(true)),
+            b"V" => Some(Self(false)),
+            _ => None,
+        }
+    }
+}
+
 impl From<&[u8]> for Status {
     fn from(bytes: &[u8]) -> Self {
-        Self(bytes.first().map(|&b| b).unwrap_or(b'V') == b'A')
+        Self::try_from(bytes).unwrap_or_default()
+    }
+}
+
+/// NMEA 4.10 and later
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum NavigationalStatus {
+    Safe,
+    Caution,
+    Unsafe,
+    NotValid,
+}
+
+impl NavigationalStatus {
+    pub fn try_from(bytes: &[u8]) -> Option<Self> {
+        match bytes {
+            b"S" => Some(Self::Safe),
+            b"C" => Some(Self::Caution),
+            b"U" => Some(Self::Unsafe),
+            b"V" => Some(Self::NotValid),
+            _ => None,
+        }
     }
 }
 
@@ -102,10 +141,18 @@ impl core::ops::AddAssign<i32> for IntegerDecimal {
     }
 }
 
-impl Into<f32> for IntegerDecimal {
-    fn into(self) -> f32 {
-        let number = self.0 >> 8;
-        number as f32 / self.exp() as f32
+impl core::ops::Neg for IntegerDecimal {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.real(), self.decimal_length())
+    }
+}
+
+impl From<IntegerDecimal> for f32 {
+    fn from(value: IntegerDecimal) -> Self {
+        let number = value.0 >> 8;
+        number as f32 / value.exp() as f32
     }
 }
 
@@ -115,26 +162,40 @@ impl Debug for IntegerDecimal {
     }
 }
 
-impl From<&[u8]> for IntegerDecimal {
-    fn from(bytes: &[u8]) -> Self {
-        if bytes.len() == 0 {
-            return Self::default();
-        }
-        let mut splitted = bytes.split(|&b| b == b'.');
-        let mut integer = 0;
-        if let Some(field) = splitted.next() {
-            integer = unsafe { utf8(field) }.parse().unwrap_or_default();
-        }
-        let mut decimal_length = 0;
-        let mut decimal = 0;
-        if let Some(field) = splitted.next() {
-            decimal_length = core::cmp::min(field.len(), 255);
-            decimal = unsafe { utf8(&field[..decimal_length]) }.parse().unwrap_or_default();
-            if integer < 0 {
-                decimal = -decimal
+/// Value must fit in 24 bits
+pub const MAX_INTEGER_DECIMAL: i32 = (1 << 23) - 1;
+
+/// Decimal length up to 9 so that exp fits in i32
+pub const MAX_DECIMAL_LENGTH: u8 = 9;
+
+impl TryFrom<&[u8]> for IntegerDecimal {
+    type Error = ParseError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        let (negative, digits) = match bytes.split_first() {
+            Some((b'-', digits)) => (true, digits),
+            Some((b'+', digits)) => (false, digits),
+            _ => (false, bytes),
+        };
+        let mut value: i32 = 0;
+        let mut decimal_length: u8 = 0;
+        let mut point = false;
+        for (i, &b) in digits.iter().enumerate() {
+            match b {
+                b'.' if !point && i > 0 => point = true,
+                b'0'..=b'9' if decimal_length < MAX_DECIMAL_LENGTH => {
+                    value = value * 10 + (b - b'0') as i32;
+                    if value > MAX_INTEGER_DECIMAL {
+                        return Err(ParseError::InvalidField(0));
+                    }
+                    decimal_length += point as u8;
+                }
+                _ => return Err(ParseError::InvalidField(0)),
             }
         }
-        let exp = 10_i32.pow(decimal_length as u32);
-        Self::new(integer * exp + decimal, decimal_length as u8)
+        if digits.is_empty() {
+            return Err(ParseError::InvalidField(0));
+        }
+        Ok(Self::new(if negative { -value } else { value }, decimal_length))
     }
 }
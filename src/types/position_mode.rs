@@ -1,39 +1,51 @@
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
 pub enum PositionMode {
+    #[default]
     NoFix,
 
     Estimated,
 
     Autonomous,
     Differential,
+    Precise,
 
     RealTimeKinematicFloat,
     RealTimeKinematicFixed,
+
+    Manual,
+    Simulator,
 }
 
-impl Default for PositionMode {
-    fn default() -> Self {
-        Self::NoFix
+impl PositionMode {
+    /// Accepts GGA quality digit as well as mode indicator letter
+    pub fn try_from(bytes: &[u8]) -> Option<Self> {
+        match bytes {
+            b"0" => Some(Self::NoFix),
+            b"1" => Some(Self::Autonomous),
+            b"2" => Some(Self::Differential),
+            b"3" => Some(Self::Precise),
+            b"4" => Some(Self::RealTimeKinematicFixed),
+            b"5" => Some(Self::RealTimeKinematicFloat),
+            b"6" => Some(Self::Estimated),
+            b"7" => Some(Self::Manual),
+            b"8" => Some(Self::Simulator),
+
+            b"N" => Some(Self::NoFix),
+            b"E" => Some(Self::Estimated),
+            b"F" => Some(Self::RealTimeKinematicFloat),
+            b"R" => Some(Self::RealTimeKinematicFixed),
+            b"A" => Some(Self::Autonomous),
+            b"D" => Some(Self::Differential),
+            b"P" => Some(Self::Precise),
+            b"M" => Some(Self::Manual),
+            b"S" => Some(Self::Simulator),
+            _ => None,
+        }
     }
 }
 
 impl From<&[u8]> for PositionMode {
     fn from(bytes: &[u8]) -> Self {
-        match bytes.first().map(|&b| b).unwrap_or(b'0') {
-            b'0' => Self::NoFix,
-            b'1' => Self::Autonomous,
-            b'2' => Self::Differential,
-            b'4' => Self::RealTimeKinematicFixed,
-            b'5' => Self::RealTimeKinematicFloat,
-            b'6' => Self::Estimated,
-
-            b'N' => Self::NoFix,
-            b'E' => Self::Estimated,
-            b'F' => Self::RealTimeKinematicFloat,
-            b'R' => Self::RealTimeKinematicFixed,
-            b'A' => Self::Autonomous,
-            b'D' => Self::Differential,
-            _ => Self::NoFix,
-        }
+        Self::try_from(bytes).unwrap_or_default()
     }
 }
@@ -0,0 +1,77 @@
+use core::str::from_utf8_unchecked as utf8;
+
+/// ASCII string stored inline with capacity of N bytes
+#[derive(Copy, Clone)]
+pub struct FixedString<const N: usize> {
+    bytes: [u8; N],
+    length: usize,
+}
+
+impl<const N: usize> Default for FixedString<N> {
+    fn default() -> Self {
+        Self { bytes: [0u8; N], length: 0 }
+    }
+}
+
+impl<const N: usize> FixedString<N> {
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.length == N
+    }
+
+    pub fn clear(&mut self) {
+        self.length = 0;
+    }
+
+    /// Returns false if string is full or byte is not ASCII
+    pub fn push(&mut self, byte: u8) -> bool {
+        if self.length >= N || !byte.is_ascii() {
+            return false;
+        }
+        self.bytes[self.length] = byte;
+        self.length += 1;
+        true
+    }
+
+    /// Appends as many bytes as possible, returns false if any byte is dropped
+    pub fn push_bytes(&mut self, bytes: &[u8]) -> bool {
+        let mut all = true;
+        for &byte in bytes.iter() {
+            all &= self.push(byte);
+        }
+        all
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.length]
+    }
+
+    pub fn as_str(&self) -> &str {
+        unsafe { utf8(self.as_bytes()) }
+    }
+}
+
+impl<const N: usize> PartialEq for FixedString<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl<const N: usize> core::fmt::Display for FixedString<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl<const N: usize> core::fmt::Debug for FixedString<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{:?}", self.as_str())
+    }
+}
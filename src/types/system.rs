@@ -0,0 +1,35 @@
+/// GNSS system ID as defined by NMEA 4.10 and later
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum System {
+    GPS,
+    GLONASS,
+    Galileo,
+    BeiDou,
+    QZSS,
+    NavIC,
+}
+
+impl System {
+    pub fn from_id(id: u8) -> Option<Self> {
+        match id {
+            1 => Some(Self::GPS),
+            2 => Some(Self::GLONASS),
+            3 => Some(Self::Galileo),
+            4 => Some(Self::BeiDou),
+            5 => Some(Self::QZSS),
+            6 => Some(Self::NavIC),
+            _ => None,
+        }
+    }
+
+    pub fn id(self) -> u8 {
+        match self {
+            Self::GPS => 1,
+            Self::GLONASS => 2,
+            Self::Galileo => 3,
+            Self::BeiDou => 4,
+            Self::QZSS => 5,
+            Self::NavIC => 6,
+        }
+    }
+}
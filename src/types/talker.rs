@@ -0,0 +1,105 @@
+use crate::types::system::System;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TalkerId {
+    /// GPS
+    GP,
+    /// GLONASS
+    GL,
+    /// Galileo
+    GA,
+    /// BeiDou
+    GB,
+    /// BeiDou, used by some receivers instead of GB
+    BD,
+    /// QZSS
+    GQ,
+    /// NavIC
+    GI,
+    /// combination of multiple systems
+    GN,
+    /// integrated instrumentation
+    II,
+    /// integrated navigation
+    IN,
+    /// north seeking gyro
+    HE,
+    /// magnetic compass
+    HC,
+    /// weather instruments
+    WI,
+    /// AIS
+    AI,
+    /// ECDIS
+    EC,
+    /// depth sounder
+    SD,
+    Other([u8; 2]),
+}
+
+impl TalkerId {
+    pub fn system(self) -> Option<System> {
+        match self {
+            Self::GP => Some(System::GPS),
+            Self::GL => Some(System::GLONASS),
+            Self::GA => Some(System::Galileo),
+            Self::GB | Self::BD => Some(System::BeiDou),
+            Self::GQ => Some(System::QZSS),
+            Self::GI => Some(System::NavIC),
+            _ => None,
+        }
+    }
+
+    pub fn as_bytes(self) -> [u8; 2] {
+        match self {
+            Self::GP => *b"GP",
+            Self::GL => *b"GL",
+            Self::GA => *b"GA",
+            Self::GB => *b"GB",
+            Self::BD => *b"BD",
+            Self::GQ => *b"GQ",
+            Self::GI => *b"GI",
+            Self::GN => *b"GN",
+            Self::II => *b"II",
+            Self::IN => *b"IN",
+            Self::HE => *b"HE",
+            Self::HC => *b"HC",
+            Self::WI => *b"WI",
+            Self::AI => *b"AI",
+            Self::EC => *b"EC",
+            Self::SD => *b"SD",
+            Self::Other(bytes) => bytes,
+        }
+    }
+}
+
+impl From<[u8; 2]> for TalkerId {
+    fn from(bytes: [u8; 2]) -> Self {
+        match &bytes {
+            b"GP" => Self::GP,
+            b"GL" => Self::GL,
+            b"GA" => Self::GA,
+            b"GB" => Self::GB,
+            b"BD" => Self::BD,
+            b"GQ" => Self::GQ,
+            b"GI" => Self::GI,
+            b"GN" => Self::GN,
+            b"II" => Self::II,
+            b"IN" => Self::IN,
+            b"HE" => Self::HE,
+            b"HC" => Self::HC,
+            b"WI" => Self::WI,
+            b"AI" => Self::AI,
+            b"EC" => Self::EC,
+            b"SD" => Self::SD,
+            _ => Self::Other(bytes),
+        }
+    }
+}
+
+impl core::fmt::Display for TalkerId {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let bytes = self.as_bytes();
+        write!(f, "{}{}", bytes[0] as char, bytes[1] as char)
+    }
+}
@@ -1,4 +1,7 @@
-use core::str::from_utf8_unchecked as utf8;
+use core::convert::TryFrom;
+
+use crate::error::ParseError;
+use crate::fields::fixed_point;
 
 #[derive(Copy, Clone, Default, PartialEq)]
 pub struct Time {
@@ -8,28 +11,28 @@ pub struct Time {
     pub sub_seconds: u8,
 }
 
-impl From<&[u8]> for Time {
-    fn from(bytes: &[u8]) -> Self {
-        if bytes.len() == 0 {
-            return Self::default();
-        }
-        let mut splitted = bytes.split(|&b| b == b'.');
-        let hhmmss = match splitted.next() {
-            Some(field) => unsafe { utf8(field) },
-            None => return Time::default(),
-        };
-        let sub_seconds = match splitted.next() {
-            Some(field) => unsafe { utf8(field) },
-            None => return Time::default(),
+impl TryFrom<&[u8]> for Time {
+    type Error = ParseError;
+
+    /// Accepts `hhmmss` with optional fraction, truncated to 2 digits
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        let (hhmmss, sub_seconds) = match fixed_point(bytes, 2) {
+            Some(value) if bytes.iter().position(|&b| b == b'.').unwrap_or(bytes.len()) == 6 => {
+                value
+            }
+            _ => return Err(ParseError::InvalidField(0)),
         };
-        let hhmmss: u32 = hhmmss.parse().unwrap_or(0);
-        let sub_seconds: u8 = sub_seconds.parse().unwrap_or(0);
-        Time {
+        let time = Time {
             hour: (hhmmss / 10000) as u8,
             minute: ((hhmmss / 100) % 100) as u8,
             seconds: (hhmmss % 100) as u8,
-            sub_seconds,
+            sub_seconds: sub_seconds as u8,
+        };
+        // 60 seconds for leap second
+        if time.hour > 23 || time.minute > 59 || time.seconds > 60 {
+            return Err(ParseError::InvalidField(0));
         }
+        Ok(time)
     }
 }
 
@@ -0,0 +1,166 @@
+#[cfg(feature = "any-sentence")]
+use nmea0183_core::MessageRef;
+use nmea0183_core::{Message, ParseError, Parser, RawSentence, SentenceFormatter};
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+/// Sentences between start delimiter and checksum delimiter
+const SENTENCES: &[&str] = &[
+    "GPGGA,092725.00,4717.11399,N,00833.91590,E,4,12,0.7,1638.8,F,157.5,F,1.2,0031",
+    "GNGNS,103600.01,5114.51176,N,00012.29380,W,DRFAEN,20,0.6,111.5,45.6,2.0,0120,S",
+    "GNGSA,A,3,23,29,07,08,09,18,26,28,,,,,1.94,1.18,1.54,1",
+    "GPRMC,225446.33,A,4916.45,N,12311.12,W,000.5,054.7,191194,020.3,W,D,S",
+    "GPGLL,4717.11364,N,00833.91565,E,092321.00,A,A",
+    "GPVTG,054.7,T,034.4,M,005.5,N,010.2,K,A",
+    "GPGSV,3,2,10,10,07,189,,05,05,220,,09,34,274,42,18,25,309,44,1",
+    "GPZDA,201530.00,04,07,2002,-03,30",
+    "GPGST,172814.00,0.006,0.023,0.020,273.6,0.023,0.020,0.031",
+    "GPGBS,235458.00,1.4,1.3,3.1,03,,-21.4,3.8,1,0",
+    "GPDTM,999,CH,0.08,N,0.07,E,-47.7,W84",
+    "GPGRS,082632.00,1,0.54,0.83,1.00,1.02,-2.12,2.64,-0.71,-1.18,0.25,,,,1,0",
+    "GPTXT,01,01,07,a^2Cb^2Ac^5Ed^zz^2",
+    "GPALM,1,1,15,1159,00,441d,4e,16be,fd5e,a10c9f,4a2da4,686e81,58cbe1,0a4,001",
+];
+
+const FORMATTERS: &[&str] = &[
+    "GGA", "GNS", "GSA", "RMC", "GLL", "VTG", "GSV", "ZDA", "GST", "GBS", "DTM", "GRS", "TXT",
+    "ALM",
+];
+
+fn frame(body: &[u8]) -> Vec<u8> {
+    let checksum = body.iter().fold(0, |csum, &b| csum ^ b);
+    let mut bytes = vec![b'$'];
+    bytes.extend_from_slice(body);
+    bytes.extend_from_slice(format!("*{:02X}\r\n", checksum).as_bytes());
+    bytes
+}
+
+#[cfg(feature = "any-sentence")]
+fn inspect(result: Result<MessageRef, ParseError>) {
+    if let Ok(message) = result {
+        let _ = format!("{:?}", message.decode());
+        #[allow(unreachable_patterns)] // arms depend on sentence features
+        match message {
+            #[cfg(feature = "gsa")]
+            MessageRef::GSA(_, gsa) => gsa.satellites().for_each(|id| drop(format!("{:?}", id))),
+            #[cfg(feature = "gsv")]
+            MessageRef::GSV(_, gsv) => {
+                gsv.satellites().for_each(|satellite| drop(format!("{:?}", satellite)));
+                let _ = format!("{:?}", gsv.signal_id());
+            }
+            #[cfg(feature = "grs")]
+            MessageRef::GRS(_, grs) => {
+                (0..16).for_each(|i| drop(format!("{:?}", grs.residual(i))));
+            }
+            #[cfg(feature = "txt")]
+            MessageRef::TXT(_, txt) => drop(format!("{:?}", txt.text())),
+            _ => (),
+        }
+    }
+}
+
+fn parse(bytes: &[u8]) {
+    let mut parser = Parser::<128>::default();
+    for result in parser.parse_bytes(bytes) {
+        let _ = format!("{:?}", result);
+    }
+    let mut parser = Parser::new();
+    for &byte in bytes {
+        let _ = format!("{:?}", parser.push(byte));
+    }
+    parser.parse_raw_bytes(bytes, |result: Result<RawSentence, ParseError>| {
+        if let Ok(sentence) = result {
+            sentence.fields().count();
+        }
+    });
+    for line in bytes.split(|&b| b == b'\n') {
+        let _ = Message::try_from(line);
+    }
+    #[cfg(feature = "any-sentence")]
+    parse_ref(bytes);
+}
+
+/// Borrowing counterparts of parse
+#[cfg(feature = "any-sentence")]
+fn parse_ref(bytes: &[u8]) {
+    let mut parser = Parser::<128>::default();
+    parser.parse_bytes_ref(bytes, inspect);
+    let mut parser = Parser::new();
+    for &byte in bytes {
+        if let Some(result) = parser.push_ref(byte) {
+            inspect(result);
+        }
+    }
+    for line in bytes.split(|&b| b == b'\n') {
+        let _ = MessageRef::try_from(line).and_then(|message| message.decode());
+    }
+}
+
+proptest! {
+    #[test]
+    fn random_bytes(bytes in vec(any::<u8>(), 0..512)) {
+        parse(&bytes);
+    }
+
+    #[test]
+    fn mutated_sentences(
+        index in 0..SENTENCES.len(),
+        mutations in vec((any::<prop::sample::Index>(), any::<u8>()), 1..8),
+    ) {
+        let mut body = SENTENCES[index].as_bytes().to_vec();
+        for (position, byte) in mutations {
+            let position = position.index(body.len());
+            body[position] = byte;
+        }
+        let bytes = frame(&body);
+        parse(&bytes);
+        parse(&bytes[1..]);
+    }
+
+    #[test]
+    fn truncated_sentences(index in 0..SENTENCES.len(), length in any::<prop::sample::Index>()) {
+        let body = SENTENCES[index].as_bytes();
+        let body = &body[..length.index(body.len() + 1)];
+        parse(&frame(body));
+        parse(&[b"$", body, b"\r\n"].concat());
+    }
+
+    #[test]
+    fn random_fields(
+        talker in "[A-Z]{2}",
+        formatter in prop::sample::select(FORMATTERS),
+        fields in vec("[-+0-9A-Za-z.^]{0,12}", 0..24),
+    ) {
+        let body = format!("{}{},{}", talker, formatter, fields.join(","));
+        parse(&frame(body.as_bytes()));
+    }
+
+    #[test]
+    fn interleaved_sentences(indexes in vec(0..SENTENCES.len(), 1..16), splits in vec(0..96usize, 1..8)) {
+        let bytes: Vec<u8> =
+            indexes.iter().flat_map(|&index| frame(SENTENCES[index].as_bytes())).collect();
+        let mut parser = Parser::<128>::default();
+        let mut offset = 0;
+        for split in splits {
+            let end = core::cmp::min(offset + split, bytes.len());
+            parser.parse_bytes(&bytes[offset..end]).count();
+            offset = end;
+        }
+        parser.parse_bytes(&bytes[offset..]).count();
+    }
+}
+
+#[test]
+fn test_sentences() {
+    let mut parser = Parser::<128>::default();
+    for sentence in SENTENCES {
+        // sentence features left out
+        if SentenceFormatter::try_from(&sentence.as_bytes()[2..5]).is_none() {
+            continue;
+        }
+        let bytes = frame(sentence.as_bytes());
+        let results: Vec<_> = parser.parse_bytes(&bytes).collect();
+        assert_eq!(1, results.len());
+        assert!(results[0].is_ok(), "{} {:?}", sentence, results[0]);
+    }
+}